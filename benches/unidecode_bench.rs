@@ -2,11 +2,11 @@ use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use unidecode_rs::unidecode;
 
 fn dataset_short() -> &'static str {
-    "CafÃ© dÃ©jÃ  vu â€” Ğ ÑƒÑÑĞºĞ¸Ğ¹ Ñ‚ĞµĞºÑÑ‚ ä¸­æ–‡ ğŸ˜€ ğ”˜ğ”«ğ”¦ğ” ğ”¬ğ”¡ğ”¢"
+    "Café déjà vu — Русский текст 中文 😀 𝔘𝔫𝔦𝔠𝔬𝔡𝔢"
 }
 
 fn dataset_medium() -> String {
-    let base = "PchnÄ…Ä‡ w tÄ™ Å‚Ã³dÅº jeÅ¼a lub oÅ›m skrzyÅ„ fig"; // Polish pangram variant
+    let base = "Pchnąć w tę łódź jeża lub ośm skrzyń fig"; // Polish pangram variant
     let mut s = String::with_capacity(4096);
     for _ in 0..128 {
         s.push_str(base);
@@ -17,7 +17,7 @@ fn dataset_medium() -> String {
 
 fn dataset_large() -> String {
     // Mix of scripts repeated
-    let chunk = "Î£á½² Î³Î½Ï‰ÏÎ¯Î¶Ï‰ á¼€Ï€á½¸ Ï„á½´Î½ ÎºÏŒÏˆÎ· Ğ¡ÑŠĞµÑˆÑŒ ĞµÑ‰Ñ‘ ÑÑ‚Ğ¸Ñ… Ğ¼ÑĞ³ĞºĞ¸Ñ… Ñ„Ñ€Ğ°Ğ½Ñ†ÑƒĞ·ÑĞºĞ¸Ñ… Ğ±ÑƒĞ»Ğ¾Ğº ğŸ˜€ ä¸­æ–‡æ¸¬è©¦";
+    let chunk = "Σὲ γνωρίζω από την κόψη Съешь ещё этих мягких французских булок 😀 中文測試";
     let mut s = String::with_capacity(64 * 1024);
     for _ in 0..512 {
         s.push_str(chunk);
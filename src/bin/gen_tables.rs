@@ -0,0 +1,94 @@
+//! Maintainer-only generator that refreshes `tables/unidecode_data.json` from the upstream
+//! Python `Unidecode` package, mirroring how `rustc`'s own `src/etc/unicode.py` bakes Unicode
+//! data into committed source rather than regenerating it on every build. This is the only
+//! place in the crate that still shells out to `python3` / hits the network (via `pip install`);
+//! `build.rs` only ever reads the committed JSON artifact.
+//!
+//! Python's `unidecode` returns `""` both for unmapped code points and for code points that
+//! transliterate to nothing, so this binary writes only non-empty results; it never emits the
+//! empty-string entries (combining marks, U+00B0, ...) that `build.rs` can read from the artifact.
+//!
+//! Run manually with `cargo run --bin gen_tables` (gate behind a `regenerate-tables` feature
+//! with `required-features` once this crate has a `Cargo.toml`) whenever the Python `Unidecode`
+//! version being tracked changes. A default `cargo build`/`cargo test` never invokes this.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_path = crate_root.join("tables").join("unidecode_data.json");
+
+    let python = env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let mut merged: BTreeMap<u32, String> = BTreeMap::new();
+
+    // Every 256-codepoint block up to U+10FFFF, so supplementary-plane blocks such as 0x1d4
+    // or 0x1f6 are refreshed too.
+    for block in 0u32..0x1100u32 {
+        let start = block << 8;
+        let end = ((block + 1) << 8) - 1;
+
+        let py_code = format!(
+            r#"import json,sys
+from unidecode import unidecode as _u
+out={{}}
+for cp in range({start},{end}+1):
+    ch=chr(cp)
+    s=_u(ch)
+    if s:
+        out[cp]=s
+sys.stdout.reconfigure(encoding='utf-8')
+print(json.dumps(out, ensure_ascii=False))"#,
+            start = start,
+            end = end
+        );
+
+        let output = Command::new(&python)
+            .arg("-c")
+            .arg(&py_code)
+            .output()
+            .expect("failed to run python to extract Unidecode block");
+
+        let stdout = if output.status.success() {
+            output.stdout
+        } else {
+            eprintln!("python extraction failed for block {:#x}; attempting pip install Unidecode", block);
+            let install = Command::new(&python)
+                .arg("-m")
+                .arg("pip")
+                .arg("install")
+                .arg("Unidecode")
+                .output()
+                .expect("failed to run pip install Unidecode");
+            if !install.status.success() {
+                let stderr = String::from_utf8_lossy(&install.stderr);
+                panic!("pip install failed: {}", stderr);
+            }
+            let output2 = Command::new(&python)
+                .arg("-c")
+                .arg(&py_code)
+                .output()
+                .expect("failed to run python to extract Unidecode block (retry)");
+            if !output2.status.success() {
+                let stderr = String::from_utf8_lossy(&output2.stderr);
+                panic!("python extraction retry failed: {}", stderr);
+            }
+            output2.stdout
+        };
+
+        let json_text = String::from_utf8(stdout).expect("python returned non-utf8 output");
+        let block_map: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&json_text).unwrap_or_else(|e| panic!("invalid json from python for block {:02x}: {}", block, e));
+        for (k, v) in block_map {
+            let cp: u32 = k.parse().expect("invalid codepoint key from python json");
+            let s = v.as_str().expect("expected string value in unidecode json").to_string();
+            merged.insert(cp, s);
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&merged).expect("failed to serialize merged table");
+    std::fs::write(&out_path, json + "\n").expect("failed to write tables/unidecode_data.json");
+    eprintln!("wrote {} entries to {}", merged.len(), out_path.display());
+}
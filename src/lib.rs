@@ -1,7 +1,23 @@
+//! Core transliteration only needs `alloc` (for `String`) plus the generated `phf` tables, so
+//! the crate builds under `#![no_std]` whenever the `std` feature is off. The Python binding and
+//! any other `std`-only helpers stay behind their own feature gates (`python`, `fallback-deunicode`)
+//! since pyo3 itself requires `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::String, string::ToString, vec::Vec};
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::Cow, string::String, string::ToString, vec::Vec};
+
 #[cfg(feature = "fallback-deunicode")]
 use deunicode::deunicode;
 
-// Include Python bindings when building with the `python` feature.
+use unicode_normalization::UnicodeNormalization;
+
+// Include Python bindings when building with the `python` feature (requires `std`).
 #[cfg(feature = "python")]
 mod lib_py;
 
@@ -26,25 +42,81 @@ const MATH_ALPHA_OVERRIDES: &[(u32, &str)] = &[
     (0x1D4D3, "T"),(0x1D4E3, "t"),(0x1D56D, "h"),(0x1D54B, "T"),(0x1D546, "H"),(0x1D53C, "E"),(0x1D57F, "T"),(0x1D57A, "H"),(0x1D570, "E"),(0x1D7CF, "0"),(0x1D7D0, "1"),(0x1D7D1, "2"),(0x1D7D2, "3"),(0x1D7D3, "4"),(0x1D7D4, "5"),(0x1D7D5, "6"),(0x1D7D6, "7"),(0x1D7D7, "8"),(0x1D7D8, "9"),
 ];
 
-fn lookup_override(cp: u32) -> Option<&'static str> {
+/// Binary-searches a `(codepoint, ascii)` table sorted ascending by codepoint. Shared by
+/// `lookup_override` (the built-in `MATH_ALPHA_OVERRIDES`) and `Unidecoder`'s caller-supplied
+/// override slice, which must be sorted the same way.
+fn binary_search_override<'a>(table: &[(u32, &'a str)], cp: u32) -> Option<&'a str> {
     let mut lo = 0usize;
-    let mut hi = MATH_ALPHA_OVERRIDES.len();
+    let mut hi = table.len();
     while lo < hi {
         let mid = (lo + hi) / 2;
-        let (k, v) = MATH_ALPHA_OVERRIDES[mid];
+        let (k, v) = table[mid];
         if k == cp { return Some(v); }
         if k < cp { lo = mid + 1; } else { hi = mid; }
     }
     None
 }
 
+fn lookup_override(cp: u32) -> Option<&'static str> {
+    binary_search_override(MATH_ALPHA_OVERRIDES, cp)
+}
+
+/// Scalar ASCII-run scan: advances one byte at a time. Used directly when the `simd` feature
+/// is off, and as the tail fallback (for runs shorter than one SIMD register) when it's on.
+fn ascii_run_len_scalar(bytes: &[u8]) -> usize {
+    let mut i = 0usize;
+    while i < bytes.len() && bytes[i].is_ascii() { i += 1; }
+    i
+}
+
+/// Returns the length of the leading ASCII run in `bytes`. Behind the `simd` feature on
+/// `x86_64` this scans 16 bytes at a time with SSE2 (`_mm_movemask_epi8` over the high bit of
+/// each lane) and jumps straight to the first non-ASCII byte via `trailing_zeros` on the mask;
+/// otherwise it falls back to the scalar byte-at-a-time scan.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn ascii_run_len(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+
+    let mut i = 0usize;
+    while i + 16 <= bytes.len() {
+        // SAFETY: the loop guard ensures `i + 16 <= bytes.len()`, so the 16-byte unaligned
+        // load stays in bounds. SSE2 is part of the x86_64 baseline, so no runtime feature
+        // detection is required.
+        let chunk = unsafe { _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i) };
+        let mask = unsafe { _mm_movemask_epi8(chunk) } as u32;
+        if mask == 0 {
+            i += 16;
+            continue;
+        }
+        return i + mask.trailing_zeros() as usize;
+    }
+    i + ascii_run_len_scalar(&bytes[i..])
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+use ascii_run_len_scalar as ascii_run_len;
+
 /// Core transliteration (bit-for-bit equivalent to Python Unidecode for all mapped codepoints).
 ///
 /// Current micro-optimisations:
 /// - ASCII fast path: if the whole string is ASCII we return a direct clone.
 /// - Heuristic pre-allocation (~2x input length) for mixed / non-ASCII text.
-/// - Direct char iteration after an initial ASCII rejection (room for SIMD scan later).
-pub fn unidecode(input: &str) -> String { unidecode_with_policy(input, ErrorsPolicy::Default) }
+/// - ASCII runs within mixed text are scanned with `ascii_run_len`, which is SIMD-accelerated
+///   on `x86_64` behind the `simd` feature (scalar fallback otherwise).
+pub fn unidecode(input: &str) -> String { unidecode_cow(input).into_owned() }
+
+/// Zero-copy variant of [`unidecode`]: borrows `input` unchanged whenever it is
+/// entirely ASCII (the common case for Western text), and allocates an owned
+/// `String` as soon as the input contains any non-ASCII character; mixed text
+/// is never borrowed, even in part. Callers processing mostly-ASCII corpora can skip the vast majority
+/// of heap allocations by holding on to the `Cow` instead of eagerly calling
+/// `.into_owned()`.
+pub fn unidecode_cow(input: &str) -> Cow<'_, str> {
+    if input.is_ascii() {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(unidecode_with_policy(input, ErrorsPolicy::Default))
+}
 
 /// Error handling policy matching Python Unidecode semantics.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -53,79 +125,280 @@ pub enum ErrorsPolicy<'a> { Default, Ignore, Replace { replace: &'a str }, Prese
 /// Internal result carrying optional failure index for strict/invalid.
 struct TransliterationResult { out: String, error_index: Option<usize> }
 
-fn unidecode_with_policy(input: &str, policy: ErrorsPolicy<'_>) -> String {
-    match transliterate_internal(input, policy) { TransliterationResult { out, .. } => out }
+/// Transliterates `input` under a caller-chosen [`ErrorsPolicy`] instead of the library default
+/// (`ErrorsPolicy::Default`, used by [`unidecode`]). Unmapped codepoints are handled according to
+/// `policy`; under `ErrorsPolicy::Strict` the output silently stops at the first unmapped
+/// codepoint (it and everything after it are dropped, and no error is reported) — use
+/// [`unidecode_with_policy_result`] if you need to detect and locate the failure.
+pub fn unidecode_with_policy(input: &str, policy: ErrorsPolicy<'_>) -> String {
+    let TransliterationResult { out, .. } = transliterate_internal(input, policy);
+    out
 }
 
-/// Version returning a result used by Python binding for strict mode.
-pub(crate) fn unidecode_with_policy_result(input: &str, policy: ErrorsPolicy<'_>) -> Result<String, usize> {
+/// Like [`unidecode_with_policy`], but under `ErrorsPolicy::Strict` returns
+/// `Err(`[`UnidecodeError`]`)` identifying the first unmapped character instead of dropping it.
+/// Under any other policy this always returns `Ok`.
+pub fn unidecode_with_policy_result(input: &str, policy: ErrorsPolicy<'_>) -> Result<String, UnidecodeError> {
     let r = transliterate_internal(input, policy);
-    if let Some(idx) = r.error_index { return Err(idx); }
+    if let Some(index) = r.error_index { return Err(UnidecodeError { index }); }
     Ok(r.out)
 }
 
-fn transliterate_internal(input: &str, policy: ErrorsPolicy<'_>) -> TransliterationResult {
-    if input.is_ascii() { return TransliterationResult { out: input.to_string(), error_index: None }; }
+/// Error returned by the `_result` variants of the errors-policy-aware API
+/// (currently just [`unidecode_with_policy_result`]) when `ErrorsPolicy::Strict` encounters a
+/// codepoint with no transliteration mapping. `index` is a `char` index into the input (not a
+/// byte offset), mirroring the `index` attribute Python Unidecode's `UnidecodeError` exposes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnidecodeError {
+    pub index: usize,
+}
 
-    // Pass 1: estimate resulting length (ignoring Replace / Preserve nuances for simplicity).
-    let mut estimated = 0usize;
-    for ch in input.chars() {
-        let cp = ch as u32;
-        if let Some(s) = lookup_override(cp) { estimated += s.len(); continue; }
+impl core::fmt::Display for UnidecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "no transliteration mapping for character at index {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnidecodeError {}
+
+/// Transliterates a stream of raw Unicode code points (`u32`) rather than `&str`/`char`, so the
+/// Python binding can route lone surrogates (`0xD800..=0xDFFF`, which `char::from_u32` rejects)
+/// through the selected `ErrorsPolicy` like any other unmapped code point, instead of failing to
+/// extract a `str` at the FFI boundary in the first place. Returns the output as a code point
+/// stream too, since `ErrorsPolicy::Preserve`/`Invalid` can itself reproduce a lone surrogate
+/// that doesn't fit in a Rust `String`.
+///
+/// Only reachable with the `python` feature (the sole caller) or under `#[cfg(test)]`
+/// (exercised directly below); `allow(dead_code)` covers every other feature combination.
+#[cfg_attr(not(any(test, feature = "python")), allow(dead_code))]
+pub(crate) fn unidecode_code_points_with_policy_result(
+    code_points: &[u32],
+    policy: ErrorsPolicy<'_>,
+) -> Result<Vec<u32>, usize> {
+    let mut out: Vec<u32> = Vec::with_capacity(code_points.len());
+    for (char_index, &cp) in code_points.iter().enumerate() {
+        if let Some(s) = lookup_override(cp) { out.extend(s.chars().map(|c| c as u32)); continue; }
         if cp < 0x100 {
-            if cp < 0x80 { estimated += 1; }
-            else if let Some(s) = unidecode_table::lookup_0_255(cp) { estimated += s.len(); }
-        } else if let Some(s) = unidecode_table::lookup(cp) { estimated += s.len(); }
-        else {
-            match policy {
-                ErrorsPolicy::Replace { replace } => estimated += replace.len(),
-                ErrorsPolicy::Preserve | ErrorsPolicy::Invalid => estimated += ch.len_utf8(),
-                _ => {} // Default / Ignore / Strict drop for now
-            }
+            if cp < 0x80 { out.push(cp); continue; }
+            if let Some(s) = unidecode_table::lookup_0_255(cp) { out.extend(s.chars().map(|c| c as u32)); continue; }
+        }
+        if let Some(s) = unidecode_table::lookup(cp) { out.extend(s.chars().map(|c| c as u32)); continue; }
+        match policy {
+            ErrorsPolicy::Default | ErrorsPolicy::Ignore => {}
+            ErrorsPolicy::Replace { replace } => out.extend(replace.chars().map(|c| c as u32)),
+            ErrorsPolicy::Preserve | ErrorsPolicy::Invalid => out.push(cp),
+            ErrorsPolicy::Strict => return Err(char_index),
         }
     }
-    if estimated == 0 { estimated = input.len(); }
+    Ok(out)
+}
 
-    let mut out = String::with_capacity(estimated);
+/// Streams the transliteration of `input` directly into `out` instead of accumulating a
+/// `String`, for callers writing into a `Criterion`-sized buffer, a formatter, or a socket.
+///
+/// Walks `input` exactly like the allocating path (ASCII runs in one `write_str`, then
+/// `lookup_override` / `lookup_0_255` / `lookup` per non-ASCII codepoint under the default
+/// policy) but never builds an intermediate buffer of its own.
+pub fn unidecode_to<W: core::fmt::Write>(input: &str, out: &mut W) -> core::fmt::Result {
+    let mut error_index = None;
+    transliterate_core(input, ErrorsPolicy::Default, &[], out, &mut error_index)
+}
+
+/// Appends the transliteration of `input` to `out`, reusing its existing capacity instead of
+/// allocating a fresh `String` the way [`unidecode`] does. Lets callers amortize one buffer
+/// across millions of calls (e.g. processing a corpus line-by-line into a cleared-and-reused
+/// `String`). `String`'s `core::fmt::Write` impl is infallible, so this never fails.
+pub fn unidecode_into(input: &str, out: &mut String) {
+    let _ = unidecode_to(input, out);
+}
+
+/// Shared scan used by both the allocating (`String`) and streaming (`unidecode_to`) entry
+/// points. `error_index` is only ever set under `ErrorsPolicy::Strict`; it is a separate
+/// out-param (rather than the return value) because the return value is reserved for sink
+/// (`core::fmt::Write`) failures, which are a distinct kind of error. `extra_overrides` is
+/// consulted before `lookup_override` / the generated tables, letting `Unidecoder` customize
+/// individual codepoints without touching `MATH_ALPHA_OVERRIDES`; callers with no overrides
+/// pass `&[]`.
+fn transliterate_core<W: core::fmt::Write>(
+    input: &str,
+    policy: ErrorsPolicy<'_>,
+    extra_overrides: &[(u32, &str)],
+    out: &mut W,
+    error_index: &mut Option<usize>,
+) -> core::fmt::Result {
     let mut char_index = 0usize; // index in chars for strict error reporting
 
     let bytes = input.as_bytes();
     let mut i = 0usize;
     while i < bytes.len() {
         if bytes[i].is_ascii() {
-            let start = i; i += 1; while i < bytes.len() && bytes[i].is_ascii() { i += 1; }
-            out.push_str(&input[start..i]);
-            // count chars in run
-            char_index += input[start..i].chars().count();
+            let start = i;
+            i += ascii_run_len(&bytes[i..]);
+            out.write_str(&input[start..i])?;
+            // ASCII run: one byte == one char, no need to re-walk it as UTF-8.
+            char_index += i - start;
             continue;
         }
         let ch = input[i..].chars().next().unwrap();
         i += ch.len_utf8();
         let cp = ch as u32;
-        if let Some(s) = lookup_override(cp) { out.push_str(s); char_index += 1; continue; }
+        if let Some(s) = binary_search_override(extra_overrides, cp) { out.write_str(s)?; char_index += 1; continue; }
+        if let Some(s) = lookup_override(cp) { out.write_str(s)?; char_index += 1; continue; }
         if cp < 0x100 {
-            if cp < 0x80 { out.push(ch); char_index += 1; continue; }
-            if let Some(s) = unidecode_table::lookup_0_255(cp) { out.push_str(s); char_index += 1; continue; }
+            if cp < 0x80 { out.write_char(ch)?; char_index += 1; continue; }
+            if let Some(s) = unidecode_table::lookup_0_255(cp) { out.write_str(s)?; char_index += 1; continue; }
         }
-        if let Some(s) = unidecode_table::lookup(cp) { out.push_str(s); char_index += 1; }
+        if let Some(s) = unidecode_table::lookup(cp) { out.write_str(s)?; char_index += 1; }
         else {
             match policy {
                 ErrorsPolicy::Default | ErrorsPolicy::Ignore => { /* skip */ }
-                ErrorsPolicy::Replace { replace } => { out.push_str(replace); }
-                ErrorsPolicy::Preserve | ErrorsPolicy::Invalid => { out.push(ch); }
+                ErrorsPolicy::Replace { replace } => { out.write_str(replace)?; }
+                ErrorsPolicy::Preserve | ErrorsPolicy::Invalid => { out.write_char(ch)?; }
                 ErrorsPolicy::Strict => {
-                    return TransliterationResult { out, error_index: Some(char_index) };
+                    *error_index = Some(char_index);
+                    return Ok(());
                 }
             }
             char_index += 1;
         }
     }
-    TransliterationResult { out, error_index: None }
+    Ok(())
+}
+
+/// Pass 1 of the allocating path: estimates the resulting length (ignoring Replace / Preserve
+/// nuances for simplicity) so `transliterate_core` can fill a single right-sized `String`
+/// instead of growing one as it goes.
+fn estimate_len(input: &str, policy: ErrorsPolicy<'_>, extra_overrides: &[(u32, &str)]) -> usize {
+    let mut estimated = 0usize;
+    for ch in input.chars() {
+        let cp = ch as u32;
+        if let Some(s) = binary_search_override(extra_overrides, cp) { estimated += s.len(); continue; }
+        if let Some(s) = lookup_override(cp) { estimated += s.len(); continue; }
+        if cp < 0x100 {
+            if cp < 0x80 { estimated += 1; }
+            else if let Some(s) = unidecode_table::lookup_0_255(cp) { estimated += s.len(); }
+        } else if let Some(s) = unidecode_table::lookup(cp) { estimated += s.len(); }
+        else {
+            match policy {
+                ErrorsPolicy::Replace { replace } => estimated += replace.len(),
+                ErrorsPolicy::Preserve | ErrorsPolicy::Invalid => estimated += ch.len_utf8(),
+                _ => {} // Default / Ignore / Strict drop for now
+            }
+        }
+    }
+    if estimated == 0 { estimated = input.len(); }
+    estimated
+}
+
+fn transliterate_internal(input: &str, policy: ErrorsPolicy<'_>) -> TransliterationResult {
+    if input.is_ascii() { return TransliterationResult { out: input.to_string(), error_index: None }; }
+
+    let estimated = estimate_len(input, policy, &[]);
+    let mut out = String::with_capacity(estimated);
+    let mut error_index = None;
+    // `String`'s `core::fmt::Write` impl is infallible, so a sink error here can't happen.
+    let _ = transliterate_core(input, policy, &[], &mut out, &mut error_index);
+    TransliterationResult { out, error_index }
 }
 
 /// Legacy alias kept for internal compatibility.
 pub fn unidecode_rust(input: &str) -> String { unidecode(input) }
 
+/// Builder for customizing transliteration with caller-supplied overrides, consulted before
+/// `MATH_ALPHA_OVERRIDES` and the generated tables. Lets users complete gaps in the
+/// Mathematical Alphanumeric block, pick a different romanization for a given Cyrillic/Greek
+/// letter, or add domain-specific symbol names, without regenerating the crate's tables.
+///
+/// ```
+/// use unidecode_rs::Unidecoder;
+///
+/// let overrides: &[(u32, &str)] = &[(0x2764, "<3")]; // U+2764 HEAVY BLACK HEART
+/// let out = Unidecoder::new().with_overrides(overrides).transliterate("I \u{2764} Rust");
+/// assert_eq!(out, "I <3 Rust");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Unidecoder<'a> {
+    overrides: &'a [(u32, &'a str)],
+    policy: ErrorsPolicy<'a>,
+    normalization: NormalizationForm,
+}
+
+/// Unicode normalization to apply to the input before table lookups, via [`Unidecoder::normalize`].
+///
+/// `"e\u{0301}"` (e + combining acute accent) and `"é"` (precomposed) can otherwise diverge:
+/// the precomposed codepoint may have a dedicated mapping that the decomposed sequence misses.
+/// Selecting `Nfc` collapses decomposed sequences to their precomposed codepoint first, so they
+/// hit the same richer single-codepoint mappings; `Nfd` instead decomposes so combining marks
+/// are consistently stripped. Default behavior (`None`) stays byte-identical to Python
+/// Unidecode, which performs no normalization.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum NormalizationForm {
+    #[default]
+    None,
+    Nfc,
+    Nfd,
+}
+
+impl<'a> Unidecoder<'a> {
+    /// Starts from the same defaults as the free-standing [`unidecode`] function: no
+    /// overrides, `ErrorsPolicy::Default`, no normalization.
+    pub fn new() -> Self {
+        Unidecoder { overrides: &[], policy: ErrorsPolicy::Default, normalization: NormalizationForm::None }
+    }
+
+    /// Registers `codepoint -> ascii` overrides consulted before the generated tables.
+    /// `overrides` must be sorted ascending by codepoint, since lookups use binary search
+    /// (matching how `MATH_ALPHA_OVERRIDES` is stored).
+    pub fn with_overrides(mut self, overrides: &'a [(u32, &'a str)]) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Sets the error handling policy for unmapped codepoints (see [`ErrorsPolicy`]).
+    pub fn with_policy(mut self, policy: ErrorsPolicy<'a>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the normalization form applied to input before table lookups (see [`NormalizationForm`]).
+    pub fn normalize(mut self, form: NormalizationForm) -> Self {
+        self.normalization = form;
+        self
+    }
+
+    /// Transliterates `input` using this builder's overrides, policy, and normalization.
+    pub fn transliterate(&self, input: &str) -> String {
+        match self.normalization {
+            NormalizationForm::None => self.transliterate_normalized(input),
+            NormalizationForm::Nfc => {
+                let normalized: String = input.nfc().collect();
+                self.transliterate_normalized(&normalized)
+            }
+            NormalizationForm::Nfd => {
+                let normalized: String = input.nfd().collect();
+                self.transliterate_normalized(&normalized)
+            }
+        }
+    }
+
+    fn transliterate_normalized(&self, input: &str) -> String {
+        if input.is_ascii() { return input.to_string(); }
+
+        let estimated = estimate_len(input, self.policy, self.overrides);
+        let mut out = String::with_capacity(estimated);
+        let mut error_index = None;
+        let _ = transliterate_core(input, self.policy, self.overrides, &mut out, &mut error_index);
+        out
+    }
+}
+
+impl<'a> Default for Unidecoder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +408,23 @@ mod tests {
         assert_eq!(unidecode("déjà"), "deja");
     }
 
+    #[test]
+    fn unidecode_cow_borrows_pure_ascii() {
+        let input = "The quick brown fox";
+        match unidecode_cow(input) {
+            Cow::Borrowed(s) => assert_eq!(s, input),
+            Cow::Owned(_) => panic!("pure ASCII input should not allocate"),
+        }
+    }
+
+    #[test]
+    fn unidecode_cow_owns_on_transliteration() {
+        match unidecode_cow("déjà") {
+            Cow::Borrowed(_) => panic!("non-ASCII input should allocate"),
+            Cow::Owned(s) => assert_eq!(s, "deja"),
+        }
+    }
+
     #[test]
     fn lookup_out_of_range_block() {
         // cp beyond any generated block ( > 0xFF blocks ) -> None
@@ -172,7 +462,10 @@ mod tests {
                 if (b[byte] & (1 << bit)) != 0 {
                     let cp = ((block as u32) << 8) | idx;
                     if let Some(m) = unidecode_table::lookup(cp) {
-                        assert!(!m.is_empty());
+                        // Some codepoints (combining marks, the Cyrillic hard/soft signs, ...)
+                        // are legitimately mapped to the empty string rather than unmapped; skip
+                        // those when sampling for a "real" (non-empty) transliteration.
+                        if m.is_empty() { continue; }
                         checked += 1;
                         if checked >= 20 { break 'blocks; }
                     }
@@ -240,4 +533,117 @@ mod tests {
         assert_eq!(res2.error_index, Some(1));
         assert_eq!(res2.out, "e");
     }
+
+    #[test]
+    fn unidecode_with_policy_result_ok_under_non_strict_policies() {
+        let s = "😀";
+        assert_eq!(unidecode_with_policy_result(s, ErrorsPolicy::Ignore), Ok(String::new()));
+        assert_eq!(unidecode_with_policy_result(s, ErrorsPolicy::Preserve), Ok(s.to_string()));
+    }
+
+    #[test]
+    fn unidecode_with_policy_result_strict_reports_char_index() {
+        let err = unidecode_with_policy_result("é😀", ErrorsPolicy::Strict).unwrap_err();
+        assert_eq!(err, UnidecodeError { index: 1 });
+        assert_eq!(err.to_string(), "no transliteration mapping for character at index 1");
+    }
+
+    #[test]
+    fn unidecode_to_matches_unidecode() {
+        let mut s = String::new();
+        unidecode_to("déjà vu — 中文", &mut s).unwrap();
+        assert_eq!(s, unidecode("déjà vu — 中文"));
+    }
+
+    #[test]
+    fn unidecode_to_appends_to_existing_buffer() {
+        let mut s = String::from("prefix:");
+        unidecode_to("café", &mut s).unwrap();
+        assert_eq!(s, "prefix:cafe");
+    }
+
+    #[test]
+    fn unidecoder_override_takes_priority_over_tables() {
+        let overrides: &[(u32, &str)] = &[(0x2764, "<3")]; // U+2764 HEAVY BLACK HEART
+        let out = Unidecoder::new().with_overrides(overrides).transliterate("I \u{2764} Rust");
+        assert_eq!(out, "I <3 Rust");
+    }
+
+    #[test]
+    fn unidecoder_default_matches_free_function() {
+        assert_eq!(Unidecoder::new().transliterate("déjà"), unidecode("déjà"));
+    }
+
+    #[test]
+    fn unidecoder_override_replaces_latin1_table_mapping() {
+        // Binary search requires ascending codepoint order; single-entry tables trivially satisfy it.
+        let overrides: &[(u32, &str)] = &[(0x00E9, "E")]; // would otherwise map to "e"
+        let out = Unidecoder::new().with_overrides(overrides).transliterate("é");
+        assert_eq!(out, "E");
+    }
+
+    #[test]
+    fn code_points_surrogate_default_dropped() {
+        // A lone high surrogate has no mapping; Default/Ignore drops it like any other
+        // unmapped code point.
+        let code_points = ['a' as u32, 0xD800, 'b' as u32];
+        let out = unidecode_code_points_with_policy_result(&code_points, ErrorsPolicy::Default).unwrap();
+        assert_eq!(out, vec!['a' as u32, 'b' as u32]);
+    }
+
+    #[test]
+    fn code_points_surrogate_preserve_round_trips() {
+        let code_points = ['a' as u32, 0xD800];
+        let out = unidecode_code_points_with_policy_result(&code_points, ErrorsPolicy::Preserve).unwrap();
+        assert_eq!(out, vec!['a' as u32, 0xD800]);
+    }
+
+    #[test]
+    fn code_points_surrogate_strict_reports_index() {
+        let code_points = ['a' as u32, 0xD800];
+        let err = unidecode_code_points_with_policy_result(&code_points, ErrorsPolicy::Strict).unwrap_err();
+        assert_eq!(err, 1);
+    }
+
+    #[test]
+    fn normalization_none_matches_plain_unidecode() {
+        // Default normalization (None) leaves input untouched, matching the free function.
+        let decomposed = "e\u{0301}";
+        assert_eq!(Unidecoder::new().transliterate(decomposed), unidecode(decomposed));
+    }
+
+    #[test]
+    fn normalization_nfc_collapses_decomposed_to_precomposed_mapping() {
+        let composed = "é";
+        let decomposed = "e\u{0301}";
+        let nfc = Unidecoder::new().normalize(NormalizationForm::Nfc);
+        assert_eq!(nfc.transliterate(composed), nfc.transliterate(decomposed));
+    }
+
+    #[test]
+    fn normalization_nfd_strips_combining_marks_consistently() {
+        let composed = "é";
+        let decomposed = "e\u{0301}";
+        let nfd = Unidecoder::new().normalize(NormalizationForm::Nfd);
+        assert_eq!(nfd.transliterate(composed), nfd.transliterate(decomposed));
+        assert_eq!(nfd.transliterate(composed), "e");
+    }
+
+    #[test]
+    fn unidecode_into_appends_and_reuses_buffer() {
+        let mut buf = String::with_capacity(64);
+        unidecode_into("café", &mut buf);
+        unidecode_into(" déjà vu", &mut buf);
+        assert_eq!(buf, "cafe deja vu");
+    }
+
+    #[test]
+    fn ascii_run_len_matches_scalar_across_chunk_boundaries() {
+        for len in [0usize, 1, 15, 16, 17, 31, 32, 33, 100] {
+            let mut bytes = vec![b'a'; len];
+            bytes.push(0xFF); // non-ASCII terminator so the run length is unambiguous
+            assert_eq!(ascii_run_len(&bytes), len, "mismatch for run length {len}");
+            assert_eq!(ascii_run_len_scalar(&bytes), len, "scalar mismatch for run length {len}");
+        }
+    }
 }
@@ -4,6 +4,8 @@ use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 #[cfg(feature = "python")]
 use pyo3::{create_exception, exceptions::PyException};
+#[cfg(feature = "python")]
+use pyo3::types::{PyBytes, PyString};
 
 // Define custom exception at module level so we can construct it easily.
 #[cfg(feature = "python")]
@@ -40,11 +42,7 @@ create_exception!(unidecode_rs, UnidecodeError, PyException);
 /// UnidecodeError
 ///     If `errors="strict"` and an unmapped character is encountered. The
 ///     exception exposes an `index` attribute giving the character index.
-fn unidecode(string: &str, errors: Option<&str>, replace_str: Option<&str>) -> PyResult<String> {
-    // Attempt to extract a Rust String from the Python object. If the Python
-    // string contains unpaired surrogates, extraction may fail; in that case
-    // we fall back to encoding/decoding via 'utf-16' with 'surrogatepass'.
-    let string: String = string.to_string();
+fn unidecode(string: &Bound<'_, PyString>, errors: Option<&str>, replace_str: Option<&str>) -> PyResult<PyObject> {
     use crate::ErrorsPolicy;
     let policy = match errors.unwrap_or("") {
         "" => ErrorsPolicy::Default,
@@ -59,27 +57,97 @@ fn unidecode(string: &str, errors: Option<&str>, replace_str: Option<&str>) -> P
         "strict" => ErrorsPolicy::Strict,
         other => return Err(pyo3::exceptions::PyValueError::new_err(format!("unknown errors policy: {}", other)))
     };
-    match crate::unidecode_with_policy_result(&string, policy) {
-        Ok(s) => Ok(s),
+
+    // `string.to_str()` fails if the Python `str` carries unpaired surrogates (e.g. from
+    // `surrogatepass`-decoded data); fall back to reading its raw UTF-16 code units so the
+    // transliteration loop still runs over every code point, surrogates included.
+    let code_points = python_code_points(string)?;
+    match crate::unidecode_code_points_with_policy_result(&code_points, policy) {
+        Ok(out_code_points) => code_points_to_pystring(string.py(), &out_code_points),
         Err(idx) => {
             // Create error instance of UnidecodeError, attach index attribute, raise.
-            let mut err = UnidecodeError::new_err("unidecode strict error");
+            let err = UnidecodeError::new_err("unidecode strict error");
             Python::with_gil(|py| {
-                let _ = err.value(py).setattr("index", idx);
+                let _ = err.value_bound(py).setattr("index", idx);
             });
             Err(err)
         }
     }
 }
 
+/// Reads the raw Unicode code points of a Python `str`, including any unpaired surrogates
+/// that `Bound<PyString>::to_str()` would reject.
+#[cfg(feature = "python")]
+fn python_code_points(string: &Bound<'_, PyString>) -> PyResult<Vec<u32>> {
+    // Fast path: a well-formed `str` with no lone surrogates extracts directly.
+    if let Ok(s) = string.to_str() {
+        return Ok(s.chars().map(|c| c as u32).collect());
+    }
+
+    // Slow path: re-encode as UTF-16 with `surrogatepass` to get the raw code units, then
+    // recombine surrogate pairs into their supplementary code point; unpaired surrogates are
+    // left as-is and routed through the selected `ErrorsPolicy` like any other unmapped code
+    // point (`char::from_u32` rejects `0xD800..=0xDFFF`, so we operate on `u32` throughout).
+    let encoded = string.call_method1("encode", ("utf-16-le", "surrogatepass"))?;
+    let bytes: Vec<u8> = encoded.extract()?;
+    let units = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]]));
+
+    let mut code_points = Vec::with_capacity(bytes.len() / 2);
+    let mut units = units.peekable();
+    while let Some(unit) = units.next() {
+        if (0xD800..=0xDBFF).contains(&unit) {
+            if let Some(&next) = units.peek() {
+                if (0xDC00..=0xDFFF).contains(&next) {
+                    units.next();
+                    let cp = 0x10000 + (((unit as u32) - 0xD800) << 10) + ((next as u32) - 0xDC00);
+                    code_points.push(cp);
+                    continue;
+                }
+            }
+        }
+        code_points.push(unit as u32);
+    }
+    Ok(code_points)
+}
+
+/// Converts a stream of output code points back into a Python `str`. Most calls never produce
+/// a lone surrogate (only `errors="preserve"`/`"invalid"` can), so the common case builds a
+/// plain Rust `String` directly; the rare surrogate-carrying case round-trips through Python's
+/// `surrogatepass` codec the same way `python_code_points` reads its input.
+#[cfg(feature = "python")]
+fn code_points_to_pystring(py: Python<'_>, code_points: &[u32]) -> PyResult<PyObject> {
+    let has_lone_surrogate = code_points.iter().any(|&cp| (0xD800..=0xDFFF).contains(&cp));
+    if !has_lone_surrogate {
+        let s: String = code_points.iter().map(|&cp| char::from_u32(cp).unwrap()).collect();
+        return Ok(PyString::new_bound(py, &s).into());
+    }
+
+    let mut units: Vec<u16> = Vec::with_capacity(code_points.len());
+    for &cp in code_points {
+        if cp <= 0xFFFF {
+            units.push(cp as u16);
+        } else {
+            let cp = cp - 0x10000;
+            units.push(0xD800 + (cp >> 10) as u16);
+            units.push(0xDC00 + (cp & 0x3FF) as u16);
+        }
+    }
+    let mut bytes = Vec::with_capacity(units.len() * 2);
+    for unit in units {
+        bytes.extend_from_slice(&unit.to_le_bytes());
+    }
+    let decoded = PyBytes::new_bound(py, &bytes).call_method1("decode", ("utf-16-le", "surrogatepass"))?;
+    Ok(decoded.into())
+}
+
 #[cfg(feature = "python")]
 #[pyfunction(signature = (string, errors=None, replace_str=None), text_signature = "(string, errors=None, replace_str=None)")]
 /// Alias matching upstream: `unidecode_expect_ascii(string, errors, replace_str)`
 fn unidecode_expect_ascii(
-    string: &str,
+    string: &Bound<'_, PyString>,
     errors: Option<&str>,
     replace_str: Option<&str>,
-) -> PyResult<String> {
+) -> PyResult<PyObject> {
     unidecode(string, errors, replace_str)
 }
 
@@ -87,13 +155,81 @@ fn unidecode_expect_ascii(
 #[pyfunction(signature = (string, errors=None, replace_str=None), text_signature = "(string, errors=None, replace_str=None)")]
 /// Alias matching upstream: `unidecode_expect_nonascii(string, errors, replace_str)`
 fn unidecode_expect_nonascii(
-    string: &str,
+    string: &Bound<'_, PyString>,
     errors: Option<&str>,
     replace_str: Option<&str>,
-) -> PyResult<String> {
+) -> PyResult<PyObject> {
     unidecode(string, errors, replace_str)
 }
 
+#[cfg(feature = "python")]
+#[pyfunction(signature = (strings, errors=None, replace_str=None), text_signature = "(strings, errors=None, replace_str=None)")]
+/// Transliterates a sequence of Python `str` objects in one call, running the actual
+/// transliteration loop with the GIL released so other Python threads can make progress
+/// instead of holding the GIL once per row (e.g. cleaning a whole column of text).
+///
+/// Parameters
+/// ----------
+/// strings : Sequence[str]
+///     Rows to transliterate.
+/// errors, replace_str
+///     Same semantics as `unidecode`.
+///
+/// Returns
+/// -------
+/// list[str]
+///
+/// Raises
+/// ------
+/// UnidecodeError
+///     If `errors="strict"`; the exception exposes `row` (the failing row's index) and `index`
+///     (the failing character index within that row).
+fn unidecode_batch(
+    py: Python<'_>,
+    strings: Vec<Bound<'_, PyString>>,
+    errors: Option<&str>,
+    replace_str: Option<&str>,
+) -> PyResult<Vec<PyObject>> {
+    use crate::ErrorsPolicy;
+    let policy = match errors.unwrap_or("") {
+        "" => ErrorsPolicy::Default,
+        "ignore" => ErrorsPolicy::Ignore,
+        "replace" => {
+            let rep = replace_str.unwrap_or("?");
+            ErrorsPolicy::Replace { replace: rep }
+        }
+        "preserve" => ErrorsPolicy::Preserve,
+        "invalid" => ErrorsPolicy::Preserve,
+        "strict" => ErrorsPolicy::Strict,
+        other => return Err(pyo3::exceptions::PyValueError::new_err(format!("unknown errors policy: {}", other))),
+    };
+
+    // Read every row's code points up front (touches the GIL-bound Python objects), then run
+    // the transliteration loop itself with the GIL released.
+    let rows: Vec<Vec<u32>> = strings.iter().map(python_code_points).collect::<PyResult<_>>()?;
+
+    let results: Vec<Result<Vec<u32>, usize>> = py.allow_threads(|| {
+        rows.iter()
+            .map(|code_points| crate::unidecode_code_points_with_policy_result(code_points, policy))
+            .collect()
+    });
+
+    let mut out = Vec::with_capacity(results.len());
+    for (row, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(code_points) => out.push(code_points_to_pystring(py, &code_points)?),
+            Err(idx) => {
+                // Create error instance of UnidecodeError, attach row + index, raise.
+                let err = UnidecodeError::new_err("unidecode strict error");
+                let _ = err.value_bound(py).setattr("row", row);
+                let _ = err.value_bound(py).setattr("index", idx);
+                return Err(err);
+            }
+        }
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "python")]
 #[pymodule]
 fn unidecode_rs(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
@@ -103,7 +239,8 @@ fn unidecode_rs(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // imports transparently.
     m.add_function(wrap_pyfunction!(unidecode_expect_ascii, m)?)?;
     m.add_function(wrap_pyfunction!(unidecode_expect_nonascii, m)?)?;
-    m.add("UnidecodeError", py.get_type::<UnidecodeError>())?;
+    m.add_function(wrap_pyfunction!(unidecode_batch, m)?)?;
+    m.add("UnidecodeError", py.get_type_bound::<UnidecodeError>())?;
     let version = env!("CARGO_PKG_VERSION");
     m.setattr("__version__", version)?;
     Ok(())
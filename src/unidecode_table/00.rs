@@ -0,0 +1,100 @@
+use phf::phf_map;
+
+pub static BLOCK_00: phf::Map<u32, &str> = phf_map!{
+    160u32 => " ",
+    161u32 => "!",
+    162u32 => "c",
+    163u32 => "PS",
+    164u32 => "$",
+    165u32 => "Y",
+    166u32 => "|",
+    167u32 => "SS",
+    168u32 => "\"",
+    169u32 => "(c)",
+    170u32 => "a",
+    171u32 => "<<",
+    172u32 => "!",
+    173u32 => "",
+    174u32 => "(r)",
+    175u32 => "-",
+    176u32 => "",
+    177u32 => "+-",
+    178u32 => "2",
+    179u32 => "3",
+    180u32 => "'",
+    181u32 => "u",
+    182u32 => "P",
+    183u32 => "*",
+    184u32 => ",",
+    185u32 => "1",
+    186u32 => "o",
+    187u32 => ">>",
+    188u32 => " 1/4",
+    189u32 => " 1/2",
+    190u32 => " 3/4",
+    191u32 => "?",
+    192u32 => "A",
+    193u32 => "A",
+    194u32 => "A",
+    195u32 => "A",
+    196u32 => "A",
+    197u32 => "A",
+    198u32 => "AE",
+    199u32 => "C",
+    200u32 => "E",
+    201u32 => "E",
+    202u32 => "E",
+    203u32 => "E",
+    204u32 => "I",
+    205u32 => "I",
+    206u32 => "I",
+    207u32 => "I",
+    208u32 => "D",
+    209u32 => "N",
+    210u32 => "O",
+    211u32 => "O",
+    212u32 => "O",
+    213u32 => "O",
+    214u32 => "O",
+    215u32 => "x",
+    216u32 => "O",
+    217u32 => "U",
+    218u32 => "U",
+    219u32 => "U",
+    220u32 => "U",
+    221u32 => "Y",
+    222u32 => "Th",
+    223u32 => "ss",
+    224u32 => "a",
+    225u32 => "a",
+    226u32 => "a",
+    227u32 => "a",
+    228u32 => "a",
+    229u32 => "a",
+    230u32 => "ae",
+    231u32 => "c",
+    232u32 => "e",
+    233u32 => "e",
+    234u32 => "e",
+    235u32 => "e",
+    236u32 => "i",
+    237u32 => "i",
+    238u32 => "i",
+    239u32 => "i",
+    240u32 => "d",
+    241u32 => "n",
+    242u32 => "o",
+    243u32 => "o",
+    244u32 => "o",
+    245u32 => "o",
+    246u32 => "o",
+    247u32 => "/",
+    248u32 => "o",
+    249u32 => "u",
+    250u32 => "u",
+    251u32 => "u",
+    252u32 => "u",
+    253u32 => "y",
+    254u32 => "th",
+    255u32 => "y",
+};
@@ -0,0 +1,132 @@
+use phf::phf_map;
+
+pub static BLOCK_01: phf::Map<u32, &str> = phf_map!{
+    256u32 => "A",
+    257u32 => "a",
+    258u32 => "A",
+    259u32 => "a",
+    260u32 => "A",
+    261u32 => "a",
+    262u32 => "C",
+    263u32 => "c",
+    264u32 => "C",
+    265u32 => "c",
+    266u32 => "C",
+    267u32 => "c",
+    268u32 => "C",
+    269u32 => "c",
+    270u32 => "D",
+    271u32 => "d",
+    272u32 => "D",
+    273u32 => "d",
+    274u32 => "E",
+    275u32 => "e",
+    276u32 => "E",
+    277u32 => "e",
+    278u32 => "E",
+    279u32 => "e",
+    280u32 => "E",
+    281u32 => "e",
+    282u32 => "E",
+    283u32 => "e",
+    284u32 => "G",
+    285u32 => "g",
+    286u32 => "G",
+    287u32 => "g",
+    288u32 => "G",
+    289u32 => "g",
+    290u32 => "G",
+    291u32 => "g",
+    292u32 => "H",
+    293u32 => "h",
+    294u32 => "H",
+    295u32 => "h",
+    296u32 => "I",
+    297u32 => "i",
+    298u32 => "I",
+    299u32 => "i",
+    300u32 => "I",
+    301u32 => "i",
+    302u32 => "I",
+    303u32 => "i",
+    304u32 => "I",
+    305u32 => "i",
+    306u32 => "IJ",
+    307u32 => "ij",
+    308u32 => "J",
+    309u32 => "j",
+    310u32 => "K",
+    311u32 => "k",
+    312u32 => "k",
+    313u32 => "L",
+    314u32 => "l",
+    315u32 => "L",
+    316u32 => "l",
+    317u32 => "L",
+    318u32 => "l",
+    319u32 => "L",
+    320u32 => "l",
+    321u32 => "L",
+    322u32 => "l",
+    323u32 => "N",
+    324u32 => "n",
+    325u32 => "N",
+    326u32 => "n",
+    327u32 => "N",
+    328u32 => "n",
+    329u32 => "'n",
+    330u32 => "NG",
+    331u32 => "ng",
+    332u32 => "O",
+    333u32 => "o",
+    334u32 => "O",
+    335u32 => "o",
+    336u32 => "O",
+    337u32 => "o",
+    338u32 => "OE",
+    339u32 => "oe",
+    340u32 => "R",
+    341u32 => "r",
+    342u32 => "R",
+    343u32 => "r",
+    344u32 => "R",
+    345u32 => "r",
+    346u32 => "S",
+    347u32 => "s",
+    348u32 => "S",
+    349u32 => "s",
+    350u32 => "S",
+    351u32 => "s",
+    352u32 => "S",
+    353u32 => "s",
+    354u32 => "T",
+    355u32 => "t",
+    356u32 => "T",
+    357u32 => "t",
+    358u32 => "T",
+    359u32 => "t",
+    360u32 => "U",
+    361u32 => "u",
+    362u32 => "U",
+    363u32 => "u",
+    364u32 => "U",
+    365u32 => "u",
+    366u32 => "U",
+    367u32 => "u",
+    368u32 => "U",
+    369u32 => "u",
+    370u32 => "U",
+    371u32 => "u",
+    372u32 => "W",
+    373u32 => "w",
+    374u32 => "Y",
+    375u32 => "y",
+    376u32 => "Y",
+    377u32 => "Z",
+    378u32 => "z",
+    379u32 => "Z",
+    380u32 => "z",
+    381u32 => "Z",
+    382u32 => "z",
+    383u32 => "s",
+};
@@ -0,0 +1,181 @@
+use phf::phf_map;
+
+pub static BLOCK_03: phf::Map<u32, &str> = phf_map!{
+    768u32 => "",
+    769u32 => "",
+    770u32 => "",
+    771u32 => "",
+    772u32 => "",
+    773u32 => "",
+    774u32 => "",
+    775u32 => "",
+    776u32 => "",
+    777u32 => "",
+    778u32 => "",
+    779u32 => "",
+    780u32 => "",
+    781u32 => "",
+    782u32 => "",
+    783u32 => "",
+    784u32 => "",
+    785u32 => "",
+    786u32 => "",
+    787u32 => "",
+    788u32 => "",
+    789u32 => "",
+    790u32 => "",
+    791u32 => "",
+    792u32 => "",
+    793u32 => "",
+    794u32 => "",
+    795u32 => "",
+    796u32 => "",
+    797u32 => "",
+    798u32 => "",
+    799u32 => "",
+    800u32 => "",
+    801u32 => "",
+    802u32 => "",
+    803u32 => "",
+    804u32 => "",
+    805u32 => "",
+    806u32 => "",
+    807u32 => "",
+    808u32 => "",
+    809u32 => "",
+    810u32 => "",
+    811u32 => "",
+    812u32 => "",
+    813u32 => "",
+    814u32 => "",
+    815u32 => "",
+    816u32 => "",
+    817u32 => "",
+    818u32 => "",
+    819u32 => "",
+    820u32 => "",
+    821u32 => "",
+    822u32 => "",
+    823u32 => "",
+    824u32 => "",
+    825u32 => "",
+    826u32 => "",
+    827u32 => "",
+    828u32 => "",
+    829u32 => "",
+    830u32 => "",
+    831u32 => "",
+    832u32 => "",
+    833u32 => "",
+    834u32 => "",
+    835u32 => "",
+    836u32 => "",
+    837u32 => "",
+    838u32 => "",
+    839u32 => "",
+    840u32 => "",
+    841u32 => "",
+    842u32 => "",
+    843u32 => "",
+    844u32 => "",
+    845u32 => "",
+    846u32 => "",
+    847u32 => "",
+    848u32 => "",
+    849u32 => "",
+    850u32 => "",
+    851u32 => "",
+    852u32 => "",
+    853u32 => "",
+    854u32 => "",
+    855u32 => "",
+    856u32 => "",
+    857u32 => "",
+    858u32 => "",
+    859u32 => "",
+    860u32 => "",
+    861u32 => "",
+    862u32 => "",
+    863u32 => "",
+    864u32 => "",
+    865u32 => "",
+    866u32 => "",
+    867u32 => "",
+    868u32 => "",
+    869u32 => "",
+    870u32 => "",
+    871u32 => "",
+    872u32 => "",
+    873u32 => "",
+    874u32 => "",
+    875u32 => "",
+    876u32 => "",
+    877u32 => "",
+    878u32 => "",
+    879u32 => "",
+    902u32 => "A",
+    904u32 => "E",
+    905u32 => "E",
+    906u32 => "I",
+    908u32 => "O",
+    910u32 => "U",
+    911u32 => "O",
+    912u32 => "i",
+    913u32 => "A",
+    914u32 => "B",
+    915u32 => "G",
+    916u32 => "D",
+    917u32 => "E",
+    918u32 => "Z",
+    919u32 => "E",
+    920u32 => "Th",
+    921u32 => "I",
+    922u32 => "K",
+    923u32 => "L",
+    924u32 => "M",
+    925u32 => "N",
+    926u32 => "X",
+    927u32 => "O",
+    928u32 => "P",
+    929u32 => "R",
+    931u32 => "S",
+    932u32 => "T",
+    933u32 => "U",
+    934u32 => "Ph",
+    935u32 => "Kh",
+    936u32 => "Ps",
+    937u32 => "O",
+    940u32 => "a",
+    941u32 => "e",
+    942u32 => "e",
+    943u32 => "i",
+    944u32 => "u",
+    945u32 => "a",
+    946u32 => "b",
+    947u32 => "g",
+    948u32 => "d",
+    949u32 => "e",
+    950u32 => "z",
+    951u32 => "e",
+    952u32 => "th",
+    953u32 => "i",
+    954u32 => "k",
+    955u32 => "l",
+    956u32 => "m",
+    957u32 => "n",
+    958u32 => "x",
+    959u32 => "o",
+    960u32 => "p",
+    961u32 => "r",
+    962u32 => "s",
+    963u32 => "s",
+    964u32 => "t",
+    965u32 => "u",
+    966u32 => "ph",
+    967u32 => "kh",
+    968u32 => "ps",
+    969u32 => "o",
+    972u32 => "o",
+    973u32 => "u",
+    974u32 => "o",
+};
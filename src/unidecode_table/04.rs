@@ -0,0 +1,70 @@
+use phf::phf_map;
+
+pub static BLOCK_04: phf::Map<u32, &str> = phf_map!{
+    1025u32 => "E",
+    1040u32 => "A",
+    1041u32 => "B",
+    1042u32 => "V",
+    1043u32 => "G",
+    1044u32 => "D",
+    1045u32 => "E",
+    1046u32 => "Zh",
+    1047u32 => "Z",
+    1048u32 => "I",
+    1049u32 => "I",
+    1050u32 => "K",
+    1051u32 => "L",
+    1052u32 => "M",
+    1053u32 => "N",
+    1054u32 => "O",
+    1055u32 => "P",
+    1056u32 => "R",
+    1057u32 => "S",
+    1058u32 => "T",
+    1059u32 => "U",
+    1060u32 => "F",
+    1061u32 => "Kh",
+    1062u32 => "Ts",
+    1063u32 => "Ch",
+    1064u32 => "Sh",
+    1065u32 => "Shch",
+    1066u32 => "",
+    1067u32 => "Y",
+    1068u32 => "",
+    1069u32 => "E",
+    1070u32 => "Iu",
+    1071u32 => "Ia",
+    1072u32 => "a",
+    1073u32 => "b",
+    1074u32 => "v",
+    1075u32 => "g",
+    1076u32 => "d",
+    1077u32 => "e",
+    1078u32 => "zh",
+    1079u32 => "z",
+    1080u32 => "i",
+    1081u32 => "i",
+    1082u32 => "k",
+    1083u32 => "l",
+    1084u32 => "m",
+    1085u32 => "n",
+    1086u32 => "o",
+    1087u32 => "p",
+    1088u32 => "r",
+    1089u32 => "s",
+    1090u32 => "t",
+    1091u32 => "u",
+    1092u32 => "f",
+    1093u32 => "kh",
+    1094u32 => "ts",
+    1095u32 => "ch",
+    1096u32 => "sh",
+    1097u32 => "shch",
+    1098u32 => "",
+    1099u32 => "y",
+    1100u32 => "",
+    1101u32 => "e",
+    1102u32 => "iu",
+    1103u32 => "ia",
+    1105u32 => "e",
+};
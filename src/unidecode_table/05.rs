@@ -0,0 +1,80 @@
+use phf::phf_map;
+
+pub static BLOCK_05: phf::Map<u32, &str> = phf_map!{
+    1329u32 => "A",
+    1330u32 => "B",
+    1331u32 => "G",
+    1332u32 => "D",
+    1333u32 => "E",
+    1334u32 => "Z",
+    1335u32 => "E",
+    1336u32 => "E",
+    1337u32 => "T`",
+    1338u32 => "Zh",
+    1339u32 => "I",
+    1340u32 => "L",
+    1341u32 => "Kh",
+    1342u32 => "Ts",
+    1343u32 => "K",
+    1344u32 => "H",
+    1345u32 => "Dz",
+    1346u32 => "Gh",
+    1347u32 => "Ch",
+    1348u32 => "M",
+    1349u32 => "Y",
+    1350u32 => "N",
+    1351u32 => "Sh",
+    1352u32 => "O",
+    1353u32 => "Ch`",
+    1354u32 => "P",
+    1355u32 => "J",
+    1356u32 => "Rr",
+    1357u32 => "S",
+    1358u32 => "V",
+    1359u32 => "T",
+    1360u32 => "R",
+    1361u32 => "Ts`",
+    1362u32 => "W",
+    1363u32 => "P`",
+    1364u32 => "K`",
+    1365u32 => "O",
+    1366u32 => "F",
+    1377u32 => "a",
+    1378u32 => "b",
+    1379u32 => "g",
+    1380u32 => "d",
+    1381u32 => "e",
+    1382u32 => "z",
+    1383u32 => "e",
+    1384u32 => "e",
+    1385u32 => "t`",
+    1386u32 => "zh",
+    1387u32 => "i",
+    1388u32 => "l",
+    1389u32 => "kh",
+    1390u32 => "ts",
+    1391u32 => "k",
+    1392u32 => "h",
+    1393u32 => "dz",
+    1394u32 => "gh",
+    1395u32 => "ch",
+    1396u32 => "m",
+    1397u32 => "y",
+    1398u32 => "n",
+    1399u32 => "sh",
+    1400u32 => "o",
+    1401u32 => "ch`",
+    1402u32 => "p",
+    1403u32 => "j",
+    1404u32 => "rr",
+    1405u32 => "s",
+    1406u32 => "v",
+    1407u32 => "t",
+    1408u32 => "r",
+    1409u32 => "ts`",
+    1410u32 => "w",
+    1411u32 => "p`",
+    1412u32 => "k`",
+    1413u32 => "o",
+    1414u32 => "f",
+};
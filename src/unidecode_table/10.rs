@@ -0,0 +1,37 @@
+use phf::phf_map;
+
+pub static BLOCK_10: phf::Map<u32, &str> = phf_map!{
+    4304u32 => "a",
+    4305u32 => "b",
+    4306u32 => "g",
+    4307u32 => "d",
+    4308u32 => "e",
+    4309u32 => "v",
+    4310u32 => "z",
+    4311u32 => "t",
+    4312u32 => "i",
+    4313u32 => "k",
+    4314u32 => "l",
+    4315u32 => "m",
+    4316u32 => "n",
+    4317u32 => "o",
+    4318u32 => "p",
+    4319u32 => "zh",
+    4320u32 => "r",
+    4321u32 => "s",
+    4322u32 => "t",
+    4323u32 => "u",
+    4324u32 => "p",
+    4325u32 => "k",
+    4326u32 => "gh",
+    4327u32 => "q",
+    4328u32 => "sh",
+    4329u32 => "ch",
+    4330u32 => "ts",
+    4331u32 => "dz",
+    4332u32 => "ts",
+    4333u32 => "ch",
+    4334u32 => "kh",
+    4335u32 => "j",
+    4336u32 => "h",
+};
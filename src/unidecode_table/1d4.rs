@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_1D4: phf::Map<u32, &str> = phf_map!{
+    119808u32 => "A",
+    119809u32 => "B",
+    119810u32 => "C",
+    119811u32 => "D",
+    119812u32 => "E",
+    119813u32 => "F",
+    119814u32 => "G",
+    119815u32 => "H",
+    119816u32 => "I",
+    119817u32 => "J",
+    119818u32 => "K",
+    119819u32 => "L",
+    119820u32 => "M",
+    119821u32 => "N",
+    119822u32 => "O",
+    119823u32 => "P",
+    119824u32 => "Q",
+    119825u32 => "R",
+    119826u32 => "S",
+    119827u32 => "T",
+    119828u32 => "U",
+    119829u32 => "V",
+    119830u32 => "W",
+    119831u32 => "X",
+    119832u32 => "Y",
+    119833u32 => "Z",
+    119834u32 => "a",
+    119835u32 => "b",
+    119836u32 => "c",
+    119837u32 => "d",
+    119838u32 => "e",
+    119839u32 => "f",
+    119840u32 => "g",
+    119841u32 => "h",
+    119842u32 => "i",
+    119843u32 => "j",
+    119844u32 => "k",
+    119845u32 => "l",
+    119846u32 => "m",
+    119847u32 => "n",
+    119848u32 => "o",
+    119849u32 => "p",
+    119850u32 => "q",
+    119851u32 => "r",
+    119852u32 => "s",
+    119853u32 => "t",
+    119854u32 => "u",
+    119855u32 => "v",
+    119856u32 => "w",
+    119857u32 => "x",
+    119858u32 => "y",
+    119859u32 => "z",
+    119860u32 => "A",
+    119861u32 => "B",
+    119862u32 => "C",
+    119863u32 => "D",
+    119864u32 => "E",
+    119865u32 => "F",
+    119866u32 => "G",
+    119867u32 => "H",
+    119868u32 => "I",
+    119869u32 => "J",
+    119870u32 => "K",
+    119871u32 => "L",
+    119872u32 => "M",
+    119873u32 => "N",
+    119874u32 => "O",
+    119875u32 => "P",
+    119876u32 => "Q",
+    119877u32 => "R",
+    119878u32 => "S",
+    119879u32 => "T",
+    119880u32 => "U",
+    119881u32 => "V",
+    119882u32 => "W",
+    119883u32 => "X",
+    119884u32 => "Y",
+    119885u32 => "Z",
+    119886u32 => "a",
+    119887u32 => "b",
+    119888u32 => "c",
+    119889u32 => "d",
+    119890u32 => "e",
+    119891u32 => "f",
+    119892u32 => "g",
+    119893u32 => "h",
+    119894u32 => "i",
+    119895u32 => "j",
+    119896u32 => "k",
+    119897u32 => "l",
+    119898u32 => "m",
+    119899u32 => "n",
+    119900u32 => "o",
+    119901u32 => "p",
+    119902u32 => "q",
+    119903u32 => "r",
+    119904u32 => "s",
+    119905u32 => "t",
+    119906u32 => "u",
+    119907u32 => "v",
+    119908u32 => "w",
+    119909u32 => "x",
+    119910u32 => "y",
+    119911u32 => "z",
+    119912u32 => "A",
+    119913u32 => "B",
+    119914u32 => "C",
+    119915u32 => "D",
+    119916u32 => "E",
+    119917u32 => "F",
+    119918u32 => "G",
+    119919u32 => "H",
+    119920u32 => "I",
+    119921u32 => "J",
+    119922u32 => "K",
+    119923u32 => "L",
+    119924u32 => "M",
+    119925u32 => "N",
+    119926u32 => "O",
+    119927u32 => "P",
+    119928u32 => "Q",
+    119929u32 => "R",
+    119930u32 => "S",
+    119931u32 => "T",
+    119932u32 => "U",
+    119933u32 => "V",
+    119934u32 => "W",
+    119935u32 => "X",
+    119936u32 => "Y",
+    119937u32 => "Z",
+    119938u32 => "a",
+    119939u32 => "b",
+    119940u32 => "c",
+    119941u32 => "d",
+    119942u32 => "e",
+    119943u32 => "f",
+    119944u32 => "g",
+    119945u32 => "h",
+    119946u32 => "i",
+    119947u32 => "j",
+    119948u32 => "k",
+    119949u32 => "l",
+    119950u32 => "m",
+    119951u32 => "n",
+    119952u32 => "o",
+    119953u32 => "p",
+    119954u32 => "q",
+    119955u32 => "r",
+    119956u32 => "s",
+    119957u32 => "t",
+    119958u32 => "u",
+    119959u32 => "v",
+    119960u32 => "w",
+    119961u32 => "x",
+    119962u32 => "y",
+    119963u32 => "z",
+    119964u32 => "A",
+    119965u32 => "C",
+    119966u32 => "C",
+    119967u32 => "D",
+    119968u32 => "G",
+    119969u32 => "H",
+    119970u32 => "G",
+    119971u32 => "J",
+    119972u32 => "K",
+    119973u32 => "J",
+    119974u32 => "K",
+    119975u32 => "N",
+    119976u32 => "O",
+    119977u32 => "N",
+    119978u32 => "O",
+    119979u32 => "P",
+    119980u32 => "Q",
+    119981u32 => "S",
+    119982u32 => "S",
+    119983u32 => "T",
+    119984u32 => "U",
+    119985u32 => "V",
+    119986u32 => "W",
+    119987u32 => "X",
+    119988u32 => "Y",
+    119989u32 => "Z",
+    119990u32 => "a",
+    119991u32 => "b",
+    119992u32 => "c",
+    119993u32 => "d",
+    119994u32 => "e",
+    119995u32 => "f",
+    119996u32 => "g",
+    119997u32 => "h",
+    119998u32 => "i",
+    119999u32 => "j",
+    120000u32 => "k",
+    120001u32 => "l",
+    120002u32 => "m",
+    120003u32 => "n",
+    120004u32 => "o",
+    120005u32 => "p",
+    120006u32 => "q",
+    120007u32 => "r",
+    120008u32 => "s",
+    120009u32 => "t",
+    120010u32 => "u",
+    120011u32 => "v",
+    120012u32 => "w",
+    120013u32 => "x",
+    120014u32 => "y",
+    120015u32 => "z",
+    120016u32 => "A",
+    120017u32 => "B",
+    120018u32 => "C",
+    120019u32 => "D",
+    120020u32 => "E",
+    120021u32 => "F",
+    120022u32 => "G",
+    120023u32 => "H",
+    120024u32 => "I",
+    120025u32 => "J",
+    120026u32 => "K",
+    120027u32 => "L",
+    120028u32 => "M",
+    120029u32 => "N",
+    120030u32 => "O",
+    120031u32 => "P",
+    120032u32 => "Q",
+    120033u32 => "R",
+    120034u32 => "S",
+    120035u32 => "T",
+    120036u32 => "U",
+    120037u32 => "V",
+    120038u32 => "W",
+    120039u32 => "X",
+    120040u32 => "Y",
+    120041u32 => "Z",
+    120042u32 => "a",
+    120043u32 => "b",
+    120044u32 => "c",
+    120045u32 => "d",
+    120046u32 => "e",
+    120047u32 => "f",
+    120048u32 => "g",
+    120049u32 => "h",
+    120050u32 => "i",
+    120051u32 => "j",
+    120052u32 => "k",
+    120053u32 => "l",
+    120054u32 => "m",
+    120055u32 => "n",
+    120056u32 => "o",
+    120057u32 => "p",
+    120058u32 => "q",
+    120059u32 => "r",
+    120060u32 => "s",
+    120061u32 => "t",
+    120062u32 => "u",
+    120063u32 => "v",
+};
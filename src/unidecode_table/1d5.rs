@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_1D5: phf::Map<u32, &str> = phf_map!{
+    120064u32 => "w",
+    120065u32 => "x",
+    120066u32 => "y",
+    120067u32 => "z",
+    120068u32 => "A",
+    120069u32 => "B",
+    120070u32 => "D",
+    120071u32 => "D",
+    120072u32 => "E",
+    120073u32 => "F",
+    120074u32 => "G",
+    120075u32 => "I",
+    120076u32 => "J",
+    120077u32 => "J",
+    120078u32 => "K",
+    120079u32 => "L",
+    120080u32 => "M",
+    120081u32 => "N",
+    120082u32 => "O",
+    120083u32 => "P",
+    120084u32 => "Q",
+    120085u32 => "S",
+    120086u32 => "S",
+    120087u32 => "T",
+    120088u32 => "U",
+    120089u32 => "V",
+    120090u32 => "W",
+    120091u32 => "X",
+    120092u32 => "Y",
+    120093u32 => "a",
+    120094u32 => "a",
+    120095u32 => "b",
+    120096u32 => "c",
+    120097u32 => "d",
+    120098u32 => "e",
+    120099u32 => "f",
+    120100u32 => "g",
+    120101u32 => "h",
+    120102u32 => "i",
+    120103u32 => "j",
+    120104u32 => "k",
+    120105u32 => "l",
+    120106u32 => "m",
+    120107u32 => "n",
+    120108u32 => "o",
+    120109u32 => "p",
+    120110u32 => "q",
+    120111u32 => "r",
+    120112u32 => "s",
+    120113u32 => "t",
+    120114u32 => "u",
+    120115u32 => "v",
+    120116u32 => "w",
+    120117u32 => "x",
+    120118u32 => "y",
+    120119u32 => "z",
+    120120u32 => "A",
+    120121u32 => "B",
+    120122u32 => "C",
+    120123u32 => "D",
+    120124u32 => "E",
+    120125u32 => "F",
+    120126u32 => "G",
+    120127u32 => "H",
+    120128u32 => "I",
+    120129u32 => "J",
+    120130u32 => "K",
+    120131u32 => "L",
+    120132u32 => "M",
+    120133u32 => "N",
+    120134u32 => "O",
+    120135u32 => "P",
+    120136u32 => "Q",
+    120137u32 => "R",
+    120138u32 => "S",
+    120139u32 => "T",
+    120140u32 => "U",
+    120141u32 => "V",
+    120142u32 => "W",
+    120143u32 => "X",
+    120144u32 => "Y",
+    120145u32 => "Z",
+    120146u32 => "a",
+    120147u32 => "b",
+    120148u32 => "c",
+    120149u32 => "d",
+    120150u32 => "e",
+    120151u32 => "f",
+    120152u32 => "g",
+    120153u32 => "h",
+    120154u32 => "i",
+    120155u32 => "j",
+    120156u32 => "k",
+    120157u32 => "l",
+    120158u32 => "m",
+    120159u32 => "n",
+    120160u32 => "o",
+    120161u32 => "p",
+    120162u32 => "q",
+    120163u32 => "r",
+    120164u32 => "s",
+    120165u32 => "t",
+    120166u32 => "u",
+    120167u32 => "v",
+    120168u32 => "w",
+    120169u32 => "x",
+    120170u32 => "y",
+    120171u32 => "z",
+    120172u32 => "A",
+    120173u32 => "B",
+    120174u32 => "C",
+    120175u32 => "D",
+    120176u32 => "E",
+    120177u32 => "F",
+    120178u32 => "G",
+    120179u32 => "H",
+    120180u32 => "I",
+    120181u32 => "J",
+    120182u32 => "K",
+    120183u32 => "L",
+    120184u32 => "M",
+    120185u32 => "N",
+    120186u32 => "O",
+    120187u32 => "P",
+    120188u32 => "Q",
+    120189u32 => "R",
+    120190u32 => "S",
+    120191u32 => "T",
+    120192u32 => "U",
+    120193u32 => "V",
+    120194u32 => "W",
+    120195u32 => "X",
+    120196u32 => "Y",
+    120197u32 => "Z",
+    120198u32 => "a",
+    120199u32 => "b",
+    120200u32 => "c",
+    120201u32 => "d",
+    120202u32 => "e",
+    120203u32 => "f",
+    120204u32 => "g",
+    120205u32 => "h",
+    120206u32 => "i",
+    120207u32 => "j",
+    120208u32 => "k",
+    120209u32 => "l",
+    120210u32 => "m",
+    120211u32 => "n",
+    120212u32 => "o",
+    120213u32 => "p",
+    120214u32 => "q",
+    120215u32 => "r",
+    120216u32 => "s",
+    120217u32 => "t",
+    120218u32 => "u",
+    120219u32 => "v",
+    120220u32 => "w",
+    120221u32 => "x",
+    120222u32 => "y",
+    120223u32 => "z",
+    120224u32 => "A",
+    120225u32 => "B",
+    120226u32 => "C",
+    120227u32 => "D",
+    120228u32 => "E",
+    120229u32 => "F",
+    120230u32 => "G",
+    120231u32 => "H",
+    120232u32 => "I",
+    120233u32 => "J",
+    120234u32 => "K",
+    120235u32 => "L",
+    120236u32 => "M",
+    120237u32 => "N",
+    120238u32 => "O",
+    120239u32 => "P",
+    120240u32 => "Q",
+    120241u32 => "R",
+    120242u32 => "S",
+    120243u32 => "T",
+    120244u32 => "U",
+    120245u32 => "V",
+    120246u32 => "W",
+    120247u32 => "X",
+    120248u32 => "Y",
+    120249u32 => "Z",
+    120250u32 => "a",
+    120251u32 => "b",
+    120252u32 => "c",
+    120253u32 => "d",
+    120254u32 => "e",
+    120255u32 => "f",
+    120256u32 => "g",
+    120257u32 => "h",
+    120258u32 => "i",
+    120259u32 => "j",
+    120260u32 => "k",
+    120261u32 => "l",
+    120262u32 => "m",
+    120263u32 => "n",
+    120264u32 => "o",
+    120265u32 => "p",
+    120266u32 => "q",
+    120267u32 => "r",
+    120268u32 => "s",
+    120269u32 => "t",
+    120270u32 => "u",
+    120271u32 => "v",
+    120272u32 => "w",
+    120273u32 => "x",
+    120274u32 => "y",
+    120275u32 => "z",
+    120276u32 => "A",
+    120277u32 => "B",
+    120278u32 => "C",
+    120279u32 => "D",
+    120280u32 => "E",
+    120281u32 => "F",
+    120282u32 => "G",
+    120283u32 => "H",
+    120284u32 => "I",
+    120285u32 => "J",
+    120286u32 => "K",
+    120287u32 => "L",
+    120288u32 => "M",
+    120289u32 => "N",
+    120290u32 => "O",
+    120291u32 => "P",
+    120292u32 => "Q",
+    120293u32 => "R",
+    120294u32 => "S",
+    120295u32 => "T",
+    120296u32 => "U",
+    120297u32 => "V",
+    120298u32 => "W",
+    120299u32 => "X",
+    120300u32 => "Y",
+    120301u32 => "Z",
+    120302u32 => "a",
+    120303u32 => "b",
+    120304u32 => "c",
+    120305u32 => "d",
+    120306u32 => "e",
+    120307u32 => "f",
+    120308u32 => "g",
+    120309u32 => "h",
+    120310u32 => "i",
+    120311u32 => "j",
+    120312u32 => "k",
+    120313u32 => "l",
+    120314u32 => "m",
+    120315u32 => "n",
+    120316u32 => "o",
+    120317u32 => "p",
+    120318u32 => "q",
+    120319u32 => "r",
+};
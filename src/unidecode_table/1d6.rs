@@ -0,0 +1,168 @@
+use phf::phf_map;
+
+pub static BLOCK_1D6: phf::Map<u32, &str> = phf_map!{
+    120320u32 => "s",
+    120321u32 => "t",
+    120322u32 => "u",
+    120323u32 => "v",
+    120324u32 => "w",
+    120325u32 => "x",
+    120326u32 => "y",
+    120327u32 => "z",
+    120328u32 => "A",
+    120329u32 => "B",
+    120330u32 => "C",
+    120331u32 => "D",
+    120332u32 => "E",
+    120333u32 => "F",
+    120334u32 => "G",
+    120335u32 => "H",
+    120336u32 => "I",
+    120337u32 => "J",
+    120338u32 => "K",
+    120339u32 => "L",
+    120340u32 => "M",
+    120341u32 => "N",
+    120342u32 => "O",
+    120343u32 => "P",
+    120344u32 => "Q",
+    120345u32 => "R",
+    120346u32 => "S",
+    120347u32 => "T",
+    120348u32 => "U",
+    120349u32 => "V",
+    120350u32 => "W",
+    120351u32 => "X",
+    120352u32 => "Y",
+    120353u32 => "Z",
+    120354u32 => "a",
+    120355u32 => "b",
+    120356u32 => "c",
+    120357u32 => "d",
+    120358u32 => "e",
+    120359u32 => "f",
+    120360u32 => "g",
+    120361u32 => "h",
+    120362u32 => "i",
+    120363u32 => "j",
+    120364u32 => "k",
+    120365u32 => "l",
+    120366u32 => "m",
+    120367u32 => "n",
+    120368u32 => "o",
+    120369u32 => "p",
+    120370u32 => "q",
+    120371u32 => "r",
+    120372u32 => "s",
+    120373u32 => "t",
+    120374u32 => "u",
+    120375u32 => "v",
+    120376u32 => "w",
+    120377u32 => "x",
+    120378u32 => "y",
+    120379u32 => "z",
+    120380u32 => "A",
+    120381u32 => "B",
+    120382u32 => "C",
+    120383u32 => "D",
+    120384u32 => "E",
+    120385u32 => "F",
+    120386u32 => "G",
+    120387u32 => "H",
+    120388u32 => "I",
+    120389u32 => "J",
+    120390u32 => "K",
+    120391u32 => "L",
+    120392u32 => "M",
+    120393u32 => "N",
+    120394u32 => "O",
+    120395u32 => "P",
+    120396u32 => "Q",
+    120397u32 => "R",
+    120398u32 => "S",
+    120399u32 => "T",
+    120400u32 => "U",
+    120401u32 => "V",
+    120402u32 => "W",
+    120403u32 => "X",
+    120404u32 => "Y",
+    120405u32 => "Z",
+    120406u32 => "a",
+    120407u32 => "b",
+    120408u32 => "c",
+    120409u32 => "d",
+    120410u32 => "e",
+    120411u32 => "f",
+    120412u32 => "g",
+    120413u32 => "h",
+    120414u32 => "i",
+    120415u32 => "j",
+    120416u32 => "k",
+    120417u32 => "l",
+    120418u32 => "m",
+    120419u32 => "n",
+    120420u32 => "o",
+    120421u32 => "p",
+    120422u32 => "q",
+    120423u32 => "r",
+    120424u32 => "s",
+    120425u32 => "t",
+    120426u32 => "u",
+    120427u32 => "v",
+    120428u32 => "w",
+    120429u32 => "x",
+    120430u32 => "y",
+    120431u32 => "z",
+    120432u32 => "A",
+    120433u32 => "B",
+    120434u32 => "C",
+    120435u32 => "D",
+    120436u32 => "E",
+    120437u32 => "F",
+    120438u32 => "G",
+    120439u32 => "H",
+    120440u32 => "I",
+    120441u32 => "J",
+    120442u32 => "K",
+    120443u32 => "L",
+    120444u32 => "M",
+    120445u32 => "N",
+    120446u32 => "O",
+    120447u32 => "P",
+    120448u32 => "Q",
+    120449u32 => "R",
+    120450u32 => "S",
+    120451u32 => "T",
+    120452u32 => "U",
+    120453u32 => "V",
+    120454u32 => "W",
+    120455u32 => "X",
+    120456u32 => "Y",
+    120457u32 => "Z",
+    120458u32 => "a",
+    120459u32 => "b",
+    120460u32 => "c",
+    120461u32 => "d",
+    120462u32 => "e",
+    120463u32 => "f",
+    120464u32 => "g",
+    120465u32 => "h",
+    120466u32 => "i",
+    120467u32 => "j",
+    120468u32 => "k",
+    120469u32 => "l",
+    120470u32 => "m",
+    120471u32 => "n",
+    120472u32 => "o",
+    120473u32 => "p",
+    120474u32 => "q",
+    120475u32 => "r",
+    120476u32 => "s",
+    120477u32 => "t",
+    120478u32 => "u",
+    120479u32 => "v",
+    120480u32 => "w",
+    120481u32 => "x",
+    120482u32 => "y",
+    120483u32 => "z",
+};
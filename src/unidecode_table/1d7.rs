@@ -0,0 +1,54 @@
+use phf::phf_map;
+
+pub static BLOCK_1D7: phf::Map<u32, &str> = phf_map!{
+    120782u32 => "0",
+    120783u32 => "1",
+    120784u32 => "2",
+    120785u32 => "3",
+    120786u32 => "4",
+    120787u32 => "5",
+    120788u32 => "6",
+    120789u32 => "7",
+    120790u32 => "8",
+    120791u32 => "9",
+    120792u32 => "0",
+    120793u32 => "1",
+    120794u32 => "2",
+    120795u32 => "3",
+    120796u32 => "4",
+    120797u32 => "5",
+    120798u32 => "6",
+    120799u32 => "7",
+    120800u32 => "8",
+    120801u32 => "9",
+    120802u32 => "0",
+    120803u32 => "1",
+    120804u32 => "2",
+    120805u32 => "3",
+    120806u32 => "4",
+    120807u32 => "5",
+    120808u32 => "6",
+    120809u32 => "7",
+    120810u32 => "8",
+    120811u32 => "9",
+    120812u32 => "0",
+    120813u32 => "1",
+    120814u32 => "2",
+    120815u32 => "3",
+    120816u32 => "4",
+    120817u32 => "5",
+    120818u32 => "6",
+    120819u32 => "7",
+    120820u32 => "8",
+    120821u32 => "9",
+    120822u32 => "0",
+    120823u32 => "1",
+    120824u32 => "2",
+    120825u32 => "3",
+    120826u32 => "4",
+    120827u32 => "5",
+    120828u32 => "6",
+    120829u32 => "7",
+    120830u32 => "8",
+    120831u32 => "9",
+};
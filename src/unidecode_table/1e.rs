@@ -0,0 +1,6 @@
+use phf::phf_map;
+
+pub static BLOCK_1E: phf::Map<u32, &str> = phf_map!{
+    7873u32 => "e",
+    7895u32 => "o",
+};
@@ -0,0 +1,24 @@
+use phf::phf_map;
+
+pub static BLOCK_20: phf::Map<u32, &str> = phf_map!{
+    8208u32 => "-",
+    8209u32 => "-",
+    8210u32 => "-",
+    8211u32 => "-",
+    8212u32 => "--",
+    8213u32 => "--",
+    8216u32 => "'",
+    8217u32 => "'",
+    8218u32 => ",",
+    8219u32 => "'",
+    8220u32 => "\"",
+    8221u32 => "\"",
+    8222u32 => "\"",
+    8223u32 => "\"",
+    8224u32 => "+",
+    8225u32 => "++",
+    8226u32 => "*",
+    8230u32 => "...",
+    8242u32 => "'",
+    8243u32 => "''",
+};
@@ -0,0 +1,53 @@
+use phf::phf_map;
+
+pub static BLOCK_21: phf::Map<u32, &str> = phf_map!{
+    8451u32 => "C",
+    8457u32 => "F",
+    8528u32 => "1/7",
+    8529u32 => "1/9",
+    8530u32 => "1/10",
+    8531u32 => "1/3",
+    8532u32 => "2/3",
+    8533u32 => "1/5",
+    8534u32 => "2/5",
+    8535u32 => "3/5",
+    8536u32 => "4/5",
+    8537u32 => "1/6",
+    8538u32 => "5/6",
+    8539u32 => "1/8",
+    8540u32 => "3/8",
+    8541u32 => "5/8",
+    8542u32 => "7/8",
+    8544u32 => "I",
+    8545u32 => "II",
+    8546u32 => "III",
+    8547u32 => "IV",
+    8548u32 => "V",
+    8549u32 => "VI",
+    8550u32 => "VII",
+    8551u32 => "VIII",
+    8552u32 => "IX",
+    8553u32 => "X",
+    8554u32 => "XI",
+    8555u32 => "XII",
+    8556u32 => "L",
+    8557u32 => "C",
+    8558u32 => "D",
+    8559u32 => "M",
+    8560u32 => "i",
+    8561u32 => "ii",
+    8562u32 => "iii",
+    8563u32 => "iv",
+    8564u32 => "v",
+    8565u32 => "vi",
+    8566u32 => "vii",
+    8567u32 => "viii",
+    8568u32 => "ix",
+    8569u32 => "x",
+    8570u32 => "xi",
+    8571u32 => "xii",
+    8572u32 => "l",
+    8573u32 => "c",
+    8574u32 => "d",
+    8575u32 => "m",
+};
@@ -0,0 +1,163 @@
+use phf::phf_map;
+
+pub static BLOCK_24: phf::Map<u32, &str> = phf_map!{
+    9312u32 => "1",
+    9313u32 => "2",
+    9314u32 => "3",
+    9315u32 => "4",
+    9316u32 => "5",
+    9317u32 => "6",
+    9318u32 => "7",
+    9319u32 => "8",
+    9320u32 => "9",
+    9321u32 => "10",
+    9322u32 => "11",
+    9323u32 => "12",
+    9324u32 => "13",
+    9325u32 => "14",
+    9326u32 => "15",
+    9327u32 => "16",
+    9328u32 => "17",
+    9329u32 => "18",
+    9330u32 => "19",
+    9331u32 => "20",
+    9332u32 => "(1)",
+    9333u32 => "(2)",
+    9334u32 => "(3)",
+    9335u32 => "(4)",
+    9336u32 => "(5)",
+    9337u32 => "(6)",
+    9338u32 => "(7)",
+    9339u32 => "(8)",
+    9340u32 => "(9)",
+    9341u32 => "(10)",
+    9342u32 => "(11)",
+    9343u32 => "(12)",
+    9344u32 => "(13)",
+    9345u32 => "(14)",
+    9346u32 => "(15)",
+    9347u32 => "(16)",
+    9348u32 => "(17)",
+    9349u32 => "(18)",
+    9350u32 => "(19)",
+    9351u32 => "(20)",
+    9352u32 => "1.",
+    9353u32 => "2.",
+    9354u32 => "3.",
+    9355u32 => "4.",
+    9356u32 => "5.",
+    9357u32 => "6.",
+    9358u32 => "7.",
+    9359u32 => "8.",
+    9360u32 => "9.",
+    9361u32 => "10.",
+    9362u32 => "11.",
+    9363u32 => "12.",
+    9364u32 => "13.",
+    9365u32 => "14.",
+    9366u32 => "15.",
+    9367u32 => "16.",
+    9368u32 => "17.",
+    9369u32 => "18.",
+    9370u32 => "19.",
+    9371u32 => "20.",
+    9372u32 => "(a)",
+    9373u32 => "(b)",
+    9374u32 => "(c)",
+    9375u32 => "(d)",
+    9376u32 => "(e)",
+    9377u32 => "(f)",
+    9378u32 => "(g)",
+    9379u32 => "(h)",
+    9380u32 => "(i)",
+    9381u32 => "(j)",
+    9382u32 => "(k)",
+    9383u32 => "(l)",
+    9384u32 => "(m)",
+    9385u32 => "(n)",
+    9386u32 => "(o)",
+    9387u32 => "(p)",
+    9388u32 => "(q)",
+    9389u32 => "(r)",
+    9390u32 => "(s)",
+    9391u32 => "(t)",
+    9392u32 => "(u)",
+    9393u32 => "(v)",
+    9394u32 => "(w)",
+    9395u32 => "(x)",
+    9396u32 => "(y)",
+    9397u32 => "(z)",
+    9398u32 => "A",
+    9399u32 => "B",
+    9400u32 => "C",
+    9401u32 => "D",
+    9402u32 => "E",
+    9403u32 => "F",
+    9404u32 => "G",
+    9405u32 => "H",
+    9406u32 => "I",
+    9407u32 => "J",
+    9408u32 => "K",
+    9409u32 => "L",
+    9410u32 => "M",
+    9411u32 => "N",
+    9412u32 => "O",
+    9413u32 => "P",
+    9414u32 => "Q",
+    9415u32 => "R",
+    9416u32 => "S",
+    9417u32 => "T",
+    9418u32 => "U",
+    9419u32 => "V",
+    9420u32 => "W",
+    9421u32 => "X",
+    9422u32 => "Y",
+    9423u32 => "Z",
+    9424u32 => "a",
+    9425u32 => "b",
+    9426u32 => "c",
+    9427u32 => "d",
+    9428u32 => "e",
+    9429u32 => "f",
+    9430u32 => "g",
+    9431u32 => "h",
+    9432u32 => "i",
+    9433u32 => "j",
+    9434u32 => "k",
+    9435u32 => "l",
+    9436u32 => "m",
+    9437u32 => "n",
+    9438u32 => "o",
+    9439u32 => "p",
+    9440u32 => "q",
+    9441u32 => "r",
+    9442u32 => "s",
+    9443u32 => "t",
+    9444u32 => "u",
+    9445u32 => "v",
+    9446u32 => "w",
+    9447u32 => "x",
+    9448u32 => "y",
+    9449u32 => "z",
+    9450u32 => "0",
+    9451u32 => "11",
+    9452u32 => "12",
+    9453u32 => "13",
+    9454u32 => "14",
+    9455u32 => "15",
+    9456u32 => "16",
+    9457u32 => "17",
+    9458u32 => "18",
+    9459u32 => "19",
+    9460u32 => "20",
+    9461u32 => "1",
+    9462u32 => "2",
+    9463u32 => "3",
+    9464u32 => "4",
+    9465u32 => "5",
+    9466u32 => "6",
+    9467u32 => "7",
+    9468u32 => "8",
+    9469u32 => "9",
+    9471u32 => "0",
+};
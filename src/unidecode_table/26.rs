@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-pub static BLOCK_26: phf::Map<u32, &'static str> = phf_map!{
+pub static BLOCK_26: phf::Map<u32, &str> = phf_map!{
     9812u32 => "white king",
     9813u32 => "white queen",
     9814u32 => "white rook",
@@ -22,5 +22,4 @@ pub static BLOCK_26: phf::Map<u32, &'static str> = phf_map!{
     9830u32 => "diamonds",
     9831u32 => "clubs",
     9839u32 => "#",
-
 };
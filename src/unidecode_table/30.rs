@@ -0,0 +1,167 @@
+use phf::phf_map;
+
+pub static BLOCK_30: phf::Map<u32, &str> = phf_map!{
+    12288u32 => " ",
+    12353u32 => "a",
+    12354u32 => "a",
+    12355u32 => "i",
+    12356u32 => "i",
+    12357u32 => "u",
+    12358u32 => "u",
+    12359u32 => "e",
+    12360u32 => "e",
+    12361u32 => "o",
+    12362u32 => "o",
+    12363u32 => "ka",
+    12364u32 => "ga",
+    12365u32 => "ki",
+    12366u32 => "gi",
+    12367u32 => "ku",
+    12368u32 => "gu",
+    12369u32 => "ke",
+    12370u32 => "ge",
+    12371u32 => "ko",
+    12372u32 => "go",
+    12373u32 => "sa",
+    12374u32 => "za",
+    12375u32 => "shi",
+    12376u32 => "ji",
+    12377u32 => "su",
+    12378u32 => "zu",
+    12379u32 => "se",
+    12380u32 => "ze",
+    12381u32 => "so",
+    12382u32 => "zo",
+    12383u32 => "ta",
+    12384u32 => "da",
+    12385u32 => "chi",
+    12386u32 => "ji",
+    12387u32 => "tsu",
+    12388u32 => "tsu",
+    12389u32 => "zu",
+    12390u32 => "te",
+    12391u32 => "de",
+    12392u32 => "to",
+    12393u32 => "do",
+    12394u32 => "na",
+    12395u32 => "ni",
+    12396u32 => "nu",
+    12397u32 => "ne",
+    12398u32 => "no",
+    12399u32 => "ha",
+    12400u32 => "ba",
+    12401u32 => "pa",
+    12402u32 => "hi",
+    12403u32 => "bi",
+    12404u32 => "pi",
+    12405u32 => "fu",
+    12406u32 => "bu",
+    12407u32 => "pu",
+    12408u32 => "he",
+    12409u32 => "be",
+    12410u32 => "pe",
+    12411u32 => "ho",
+    12412u32 => "bo",
+    12413u32 => "po",
+    12414u32 => "ma",
+    12415u32 => "mi",
+    12416u32 => "mu",
+    12417u32 => "me",
+    12418u32 => "mo",
+    12419u32 => "ya",
+    12420u32 => "ya",
+    12421u32 => "yu",
+    12422u32 => "yu",
+    12423u32 => "yo",
+    12424u32 => "yo",
+    12425u32 => "ra",
+    12426u32 => "ri",
+    12427u32 => "ru",
+    12428u32 => "re",
+    12429u32 => "ro",
+    12431u32 => "wa",
+    12434u32 => "wo",
+    12435u32 => "n",
+    12449u32 => "a",
+    12450u32 => "a",
+    12451u32 => "i",
+    12452u32 => "i",
+    12453u32 => "u",
+    12454u32 => "u",
+    12455u32 => "e",
+    12456u32 => "e",
+    12457u32 => "o",
+    12458u32 => "o",
+    12459u32 => "ka",
+    12460u32 => "ga",
+    12461u32 => "ki",
+    12462u32 => "gi",
+    12463u32 => "ku",
+    12464u32 => "gu",
+    12465u32 => "ke",
+    12466u32 => "ge",
+    12467u32 => "ko",
+    12468u32 => "go",
+    12469u32 => "sa",
+    12470u32 => "za",
+    12471u32 => "shi",
+    12472u32 => "ji",
+    12473u32 => "su",
+    12474u32 => "zu",
+    12475u32 => "se",
+    12476u32 => "ze",
+    12477u32 => "so",
+    12478u32 => "zo",
+    12479u32 => "ta",
+    12480u32 => "da",
+    12481u32 => "chi",
+    12482u32 => "ji",
+    12483u32 => "tsu",
+    12484u32 => "tsu",
+    12485u32 => "zu",
+    12486u32 => "te",
+    12487u32 => "de",
+    12488u32 => "to",
+    12489u32 => "do",
+    12490u32 => "na",
+    12491u32 => "ni",
+    12492u32 => "nu",
+    12493u32 => "ne",
+    12494u32 => "no",
+    12495u32 => "ha",
+    12496u32 => "ba",
+    12497u32 => "pa",
+    12498u32 => "hi",
+    12499u32 => "bi",
+    12500u32 => "pi",
+    12501u32 => "fu",
+    12502u32 => "bu",
+    12503u32 => "pu",
+    12504u32 => "he",
+    12505u32 => "be",
+    12506u32 => "pe",
+    12507u32 => "ho",
+    12508u32 => "bo",
+    12509u32 => "po",
+    12510u32 => "ma",
+    12511u32 => "mi",
+    12512u32 => "mu",
+    12513u32 => "me",
+    12514u32 => "mo",
+    12515u32 => "ya",
+    12516u32 => "ya",
+    12517u32 => "yu",
+    12518u32 => "yu",
+    12519u32 => "yo",
+    12520u32 => "yo",
+    12521u32 => "ra",
+    12522u32 => "ri",
+    12523u32 => "ru",
+    12524u32 => "re",
+    12525u32 => "ro",
+    12527u32 => "wa",
+    12530u32 => "wo",
+    12531u32 => "n",
+    12532u32 => "vu",
+    12540u32 => "-",
+};
@@ -0,0 +1,55 @@
+use phf::phf_map;
+
+pub static BLOCK_31: phf::Map<u32, &str> = phf_map!{
+    12593u32 => "g",
+    12594u32 => "gg",
+    12595u32 => "gs",
+    12596u32 => "n",
+    12597u32 => "nj",
+    12598u32 => "nh",
+    12599u32 => "d",
+    12600u32 => "dd",
+    12601u32 => "l",
+    12602u32 => "lg",
+    12603u32 => "lm",
+    12604u32 => "lb",
+    12605u32 => "ls",
+    12606u32 => "lt",
+    12607u32 => "lp",
+    12608u32 => "lh",
+    12609u32 => "m",
+    12610u32 => "b",
+    12611u32 => "bb",
+    12612u32 => "bs",
+    12613u32 => "s",
+    12614u32 => "ss",
+    12615u32 => "",
+    12616u32 => "j",
+    12617u32 => "jj",
+    12618u32 => "c",
+    12619u32 => "k",
+    12620u32 => "t",
+    12621u32 => "p",
+    12622u32 => "h",
+    12623u32 => "a",
+    12624u32 => "ae",
+    12625u32 => "ya",
+    12626u32 => "yae",
+    12627u32 => "eo",
+    12628u32 => "e",
+    12629u32 => "yeo",
+    12630u32 => "ye",
+    12631u32 => "o",
+    12632u32 => "wa",
+    12633u32 => "wae",
+    12634u32 => "oe",
+    12635u32 => "yo",
+    12636u32 => "u",
+    12637u32 => "wo",
+    12638u32 => "we",
+    12639u32 => "wi",
+    12640u32 => "yu",
+    12641u32 => "eu",
+    12642u32 => "ui",
+    12643u32 => "i",
+};
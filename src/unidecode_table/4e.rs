@@ -0,0 +1,13 @@
+use phf::phf_map;
+
+pub static BLOCK_4E: phf::Map<u32, &str> = phf_map!{
+    19968u32 => "Yi ",
+    19977u32 => "San ",
+    19981u32 => "Bu ",
+    20010u32 => "Ge ",
+    20013u32 => "Zhong ",
+    20064u32 => "Xi ",
+    20108u32 => "Er ",
+    20116u32 => "Wu ",
+    20154u32 => "Ren ",
+};
@@ -0,0 +1,6 @@
+use phf::phf_map;
+
+pub static BLOCK_56: phf::Map<u32, &str> = phf_map!{
+    22235u32 => "Si ",
+    22269u32 => "Guo ",
+};
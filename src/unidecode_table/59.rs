@@ -0,0 +1,5 @@
+use phf::phf_map;
+
+pub static BLOCK_59: phf::Map<u32, &str> = phf_map!{
+    22823u32 => "Da ",
+};
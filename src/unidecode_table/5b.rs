@@ -0,0 +1,6 @@
+use phf::phf_map;
+
+pub static BLOCK_5B: phf::Map<u32, &str> = phf_map!{
+    23383u32 => "Zi ",
+    23398u32 => "Xue ",
+};
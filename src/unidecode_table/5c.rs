@@ -0,0 +1,5 @@
+use phf::phf_map;
+
+pub static BLOCK_5C: phf::Map<u32, &str> = phf_map!{
+    23567u32 => "Xiao ",
+};
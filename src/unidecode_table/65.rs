@@ -0,0 +1,6 @@
+use phf::phf_map;
+
+pub static BLOCK_65: phf::Map<u32, &str> = phf_map!{
+    25991u32 => "Wen ",
+    26085u32 => "Ri ",
+};
@@ -0,0 +1,5 @@
+use phf::phf_map;
+
+pub static BLOCK_66: phf::Map<u32, &str> = phf_map!{
+    26159u32 => "Shi ",
+};
@@ -0,0 +1,5 @@
+use phf::phf_map;
+
+pub static BLOCK_8B: phf::Map<u32, &str> = phf_map!{
+    35821u32 => "Yu ",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_AC: phf::Map<u32, &str> = phf_map!{
+    44032u32 => "ga",
+    44033u32 => "gak",
+    44034u32 => "gak",
+    44035u32 => "gak",
+    44036u32 => "gan",
+    44037u32 => "gan",
+    44038u32 => "gan",
+    44039u32 => "gat",
+    44040u32 => "gal",
+    44041u32 => "gak",
+    44042u32 => "gam",
+    44043u32 => "gal",
+    44044u32 => "gal",
+    44045u32 => "gal",
+    44046u32 => "gap",
+    44047u32 => "gal",
+    44048u32 => "gam",
+    44049u32 => "gap",
+    44050u32 => "gap",
+    44051u32 => "gat",
+    44052u32 => "gat",
+    44053u32 => "gang",
+    44054u32 => "gat",
+    44055u32 => "gat",
+    44056u32 => "gak",
+    44057u32 => "gat",
+    44058u32 => "gap",
+    44059u32 => "gat",
+    44060u32 => "gae",
+    44061u32 => "gaek",
+    44062u32 => "gaek",
+    44063u32 => "gaek",
+    44064u32 => "gaen",
+    44065u32 => "gaen",
+    44066u32 => "gaen",
+    44067u32 => "gaet",
+    44068u32 => "gael",
+    44069u32 => "gaek",
+    44070u32 => "gaem",
+    44071u32 => "gael",
+    44072u32 => "gael",
+    44073u32 => "gael",
+    44074u32 => "gaep",
+    44075u32 => "gael",
+    44076u32 => "gaem",
+    44077u32 => "gaep",
+    44078u32 => "gaep",
+    44079u32 => "gaet",
+    44080u32 => "gaet",
+    44081u32 => "gaeng",
+    44082u32 => "gaet",
+    44083u32 => "gaet",
+    44084u32 => "gaek",
+    44085u32 => "gaet",
+    44086u32 => "gaep",
+    44087u32 => "gaet",
+    44088u32 => "gya",
+    44089u32 => "gyak",
+    44090u32 => "gyak",
+    44091u32 => "gyak",
+    44092u32 => "gyan",
+    44093u32 => "gyan",
+    44094u32 => "gyan",
+    44095u32 => "gyat",
+    44096u32 => "gyal",
+    44097u32 => "gyak",
+    44098u32 => "gyam",
+    44099u32 => "gyal",
+    44100u32 => "gyal",
+    44101u32 => "gyal",
+    44102u32 => "gyap",
+    44103u32 => "gyal",
+    44104u32 => "gyam",
+    44105u32 => "gyap",
+    44106u32 => "gyap",
+    44107u32 => "gyat",
+    44108u32 => "gyat",
+    44109u32 => "gyang",
+    44110u32 => "gyat",
+    44111u32 => "gyat",
+    44112u32 => "gyak",
+    44113u32 => "gyat",
+    44114u32 => "gyap",
+    44115u32 => "gyat",
+    44116u32 => "gyae",
+    44117u32 => "gyaek",
+    44118u32 => "gyaek",
+    44119u32 => "gyaek",
+    44120u32 => "gyaen",
+    44121u32 => "gyaen",
+    44122u32 => "gyaen",
+    44123u32 => "gyaet",
+    44124u32 => "gyael",
+    44125u32 => "gyaek",
+    44126u32 => "gyaem",
+    44127u32 => "gyael",
+    44128u32 => "gyael",
+    44129u32 => "gyael",
+    44130u32 => "gyaep",
+    44131u32 => "gyael",
+    44132u32 => "gyaem",
+    44133u32 => "gyaep",
+    44134u32 => "gyaep",
+    44135u32 => "gyaet",
+    44136u32 => "gyaet",
+    44137u32 => "gyaeng",
+    44138u32 => "gyaet",
+    44139u32 => "gyaet",
+    44140u32 => "gyaek",
+    44141u32 => "gyaet",
+    44142u32 => "gyaep",
+    44143u32 => "gyaet",
+    44144u32 => "geo",
+    44145u32 => "geok",
+    44146u32 => "geok",
+    44147u32 => "geok",
+    44148u32 => "geon",
+    44149u32 => "geon",
+    44150u32 => "geon",
+    44151u32 => "geot",
+    44152u32 => "geol",
+    44153u32 => "geok",
+    44154u32 => "geom",
+    44155u32 => "geol",
+    44156u32 => "geol",
+    44157u32 => "geol",
+    44158u32 => "geop",
+    44159u32 => "geol",
+    44160u32 => "geom",
+    44161u32 => "geop",
+    44162u32 => "geop",
+    44163u32 => "geot",
+    44164u32 => "geot",
+    44165u32 => "geong",
+    44166u32 => "geot",
+    44167u32 => "geot",
+    44168u32 => "geok",
+    44169u32 => "geot",
+    44170u32 => "geop",
+    44171u32 => "geot",
+    44172u32 => "ge",
+    44173u32 => "gek",
+    44174u32 => "gek",
+    44175u32 => "gek",
+    44176u32 => "gen",
+    44177u32 => "gen",
+    44178u32 => "gen",
+    44179u32 => "get",
+    44180u32 => "gel",
+    44181u32 => "gek",
+    44182u32 => "gem",
+    44183u32 => "gel",
+    44184u32 => "gel",
+    44185u32 => "gel",
+    44186u32 => "gep",
+    44187u32 => "gel",
+    44188u32 => "gem",
+    44189u32 => "gep",
+    44190u32 => "gep",
+    44191u32 => "get",
+    44192u32 => "get",
+    44193u32 => "geng",
+    44194u32 => "get",
+    44195u32 => "get",
+    44196u32 => "gek",
+    44197u32 => "get",
+    44198u32 => "gep",
+    44199u32 => "get",
+    44200u32 => "gyeo",
+    44201u32 => "gyeok",
+    44202u32 => "gyeok",
+    44203u32 => "gyeok",
+    44204u32 => "gyeon",
+    44205u32 => "gyeon",
+    44206u32 => "gyeon",
+    44207u32 => "gyeot",
+    44208u32 => "gyeol",
+    44209u32 => "gyeok",
+    44210u32 => "gyeom",
+    44211u32 => "gyeol",
+    44212u32 => "gyeol",
+    44213u32 => "gyeol",
+    44214u32 => "gyeop",
+    44215u32 => "gyeol",
+    44216u32 => "gyeom",
+    44217u32 => "gyeop",
+    44218u32 => "gyeop",
+    44219u32 => "gyeot",
+    44220u32 => "gyeot",
+    44221u32 => "gyeong",
+    44222u32 => "gyeot",
+    44223u32 => "gyeot",
+    44224u32 => "gyeok",
+    44225u32 => "gyeot",
+    44226u32 => "gyeop",
+    44227u32 => "gyeot",
+    44228u32 => "gye",
+    44229u32 => "gyek",
+    44230u32 => "gyek",
+    44231u32 => "gyek",
+    44232u32 => "gyen",
+    44233u32 => "gyen",
+    44234u32 => "gyen",
+    44235u32 => "gyet",
+    44236u32 => "gyel",
+    44237u32 => "gyek",
+    44238u32 => "gyem",
+    44239u32 => "gyel",
+    44240u32 => "gyel",
+    44241u32 => "gyel",
+    44242u32 => "gyep",
+    44243u32 => "gyel",
+    44244u32 => "gyem",
+    44245u32 => "gyep",
+    44246u32 => "gyep",
+    44247u32 => "gyet",
+    44248u32 => "gyet",
+    44249u32 => "gyeng",
+    44250u32 => "gyet",
+    44251u32 => "gyet",
+    44252u32 => "gyek",
+    44253u32 => "gyet",
+    44254u32 => "gyep",
+    44255u32 => "gyet",
+    44256u32 => "go",
+    44257u32 => "gok",
+    44258u32 => "gok",
+    44259u32 => "gok",
+    44260u32 => "gon",
+    44261u32 => "gon",
+    44262u32 => "gon",
+    44263u32 => "got",
+    44264u32 => "gol",
+    44265u32 => "gok",
+    44266u32 => "gom",
+    44267u32 => "gol",
+    44268u32 => "gol",
+    44269u32 => "gol",
+    44270u32 => "gop",
+    44271u32 => "gol",
+    44272u32 => "gom",
+    44273u32 => "gop",
+    44274u32 => "gop",
+    44275u32 => "got",
+    44276u32 => "got",
+    44277u32 => "gong",
+    44278u32 => "got",
+    44279u32 => "got",
+    44280u32 => "gok",
+    44281u32 => "got",
+    44282u32 => "gop",
+    44283u32 => "got",
+    44284u32 => "gwa",
+    44285u32 => "gwak",
+    44286u32 => "gwak",
+    44287u32 => "gwak",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_AD: phf::Map<u32, &str> = phf_map!{
+    44288u32 => "gwan",
+    44289u32 => "gwan",
+    44290u32 => "gwan",
+    44291u32 => "gwat",
+    44292u32 => "gwal",
+    44293u32 => "gwak",
+    44294u32 => "gwam",
+    44295u32 => "gwal",
+    44296u32 => "gwal",
+    44297u32 => "gwal",
+    44298u32 => "gwap",
+    44299u32 => "gwal",
+    44300u32 => "gwam",
+    44301u32 => "gwap",
+    44302u32 => "gwap",
+    44303u32 => "gwat",
+    44304u32 => "gwat",
+    44305u32 => "gwang",
+    44306u32 => "gwat",
+    44307u32 => "gwat",
+    44308u32 => "gwak",
+    44309u32 => "gwat",
+    44310u32 => "gwap",
+    44311u32 => "gwat",
+    44312u32 => "gwae",
+    44313u32 => "gwaek",
+    44314u32 => "gwaek",
+    44315u32 => "gwaek",
+    44316u32 => "gwaen",
+    44317u32 => "gwaen",
+    44318u32 => "gwaen",
+    44319u32 => "gwaet",
+    44320u32 => "gwael",
+    44321u32 => "gwaek",
+    44322u32 => "gwaem",
+    44323u32 => "gwael",
+    44324u32 => "gwael",
+    44325u32 => "gwael",
+    44326u32 => "gwaep",
+    44327u32 => "gwael",
+    44328u32 => "gwaem",
+    44329u32 => "gwaep",
+    44330u32 => "gwaep",
+    44331u32 => "gwaet",
+    44332u32 => "gwaet",
+    44333u32 => "gwaeng",
+    44334u32 => "gwaet",
+    44335u32 => "gwaet",
+    44336u32 => "gwaek",
+    44337u32 => "gwaet",
+    44338u32 => "gwaep",
+    44339u32 => "gwaet",
+    44340u32 => "goe",
+    44341u32 => "goek",
+    44342u32 => "goek",
+    44343u32 => "goek",
+    44344u32 => "goen",
+    44345u32 => "goen",
+    44346u32 => "goen",
+    44347u32 => "goet",
+    44348u32 => "goel",
+    44349u32 => "goek",
+    44350u32 => "goem",
+    44351u32 => "goel",
+    44352u32 => "goel",
+    44353u32 => "goel",
+    44354u32 => "goep",
+    44355u32 => "goel",
+    44356u32 => "goem",
+    44357u32 => "goep",
+    44358u32 => "goep",
+    44359u32 => "goet",
+    44360u32 => "goet",
+    44361u32 => "goeng",
+    44362u32 => "goet",
+    44363u32 => "goet",
+    44364u32 => "goek",
+    44365u32 => "goet",
+    44366u32 => "goep",
+    44367u32 => "goet",
+    44368u32 => "gyo",
+    44369u32 => "gyok",
+    44370u32 => "gyok",
+    44371u32 => "gyok",
+    44372u32 => "gyon",
+    44373u32 => "gyon",
+    44374u32 => "gyon",
+    44375u32 => "gyot",
+    44376u32 => "gyol",
+    44377u32 => "gyok",
+    44378u32 => "gyom",
+    44379u32 => "gyol",
+    44380u32 => "gyol",
+    44381u32 => "gyol",
+    44382u32 => "gyop",
+    44383u32 => "gyol",
+    44384u32 => "gyom",
+    44385u32 => "gyop",
+    44386u32 => "gyop",
+    44387u32 => "gyot",
+    44388u32 => "gyot",
+    44389u32 => "gyong",
+    44390u32 => "gyot",
+    44391u32 => "gyot",
+    44392u32 => "gyok",
+    44393u32 => "gyot",
+    44394u32 => "gyop",
+    44395u32 => "gyot",
+    44396u32 => "gu",
+    44397u32 => "guk",
+    44398u32 => "guk",
+    44399u32 => "guk",
+    44400u32 => "gun",
+    44401u32 => "gun",
+    44402u32 => "gun",
+    44403u32 => "gut",
+    44404u32 => "gul",
+    44405u32 => "guk",
+    44406u32 => "gum",
+    44407u32 => "gul",
+    44408u32 => "gul",
+    44409u32 => "gul",
+    44410u32 => "gup",
+    44411u32 => "gul",
+    44412u32 => "gum",
+    44413u32 => "gup",
+    44414u32 => "gup",
+    44415u32 => "gut",
+    44416u32 => "gut",
+    44417u32 => "gung",
+    44418u32 => "gut",
+    44419u32 => "gut",
+    44420u32 => "guk",
+    44421u32 => "gut",
+    44422u32 => "gup",
+    44423u32 => "gut",
+    44424u32 => "gwo",
+    44425u32 => "gwok",
+    44426u32 => "gwok",
+    44427u32 => "gwok",
+    44428u32 => "gwon",
+    44429u32 => "gwon",
+    44430u32 => "gwon",
+    44431u32 => "gwot",
+    44432u32 => "gwol",
+    44433u32 => "gwok",
+    44434u32 => "gwom",
+    44435u32 => "gwol",
+    44436u32 => "gwol",
+    44437u32 => "gwol",
+    44438u32 => "gwop",
+    44439u32 => "gwol",
+    44440u32 => "gwom",
+    44441u32 => "gwop",
+    44442u32 => "gwop",
+    44443u32 => "gwot",
+    44444u32 => "gwot",
+    44445u32 => "gwong",
+    44446u32 => "gwot",
+    44447u32 => "gwot",
+    44448u32 => "gwok",
+    44449u32 => "gwot",
+    44450u32 => "gwop",
+    44451u32 => "gwot",
+    44452u32 => "gwe",
+    44453u32 => "gwek",
+    44454u32 => "gwek",
+    44455u32 => "gwek",
+    44456u32 => "gwen",
+    44457u32 => "gwen",
+    44458u32 => "gwen",
+    44459u32 => "gwet",
+    44460u32 => "gwel",
+    44461u32 => "gwek",
+    44462u32 => "gwem",
+    44463u32 => "gwel",
+    44464u32 => "gwel",
+    44465u32 => "gwel",
+    44466u32 => "gwep",
+    44467u32 => "gwel",
+    44468u32 => "gwem",
+    44469u32 => "gwep",
+    44470u32 => "gwep",
+    44471u32 => "gwet",
+    44472u32 => "gwet",
+    44473u32 => "gweng",
+    44474u32 => "gwet",
+    44475u32 => "gwet",
+    44476u32 => "gwek",
+    44477u32 => "gwet",
+    44478u32 => "gwep",
+    44479u32 => "gwet",
+    44480u32 => "gwi",
+    44481u32 => "gwik",
+    44482u32 => "gwik",
+    44483u32 => "gwik",
+    44484u32 => "gwin",
+    44485u32 => "gwin",
+    44486u32 => "gwin",
+    44487u32 => "gwit",
+    44488u32 => "gwil",
+    44489u32 => "gwik",
+    44490u32 => "gwim",
+    44491u32 => "gwil",
+    44492u32 => "gwil",
+    44493u32 => "gwil",
+    44494u32 => "gwip",
+    44495u32 => "gwil",
+    44496u32 => "gwim",
+    44497u32 => "gwip",
+    44498u32 => "gwip",
+    44499u32 => "gwit",
+    44500u32 => "gwit",
+    44501u32 => "gwing",
+    44502u32 => "gwit",
+    44503u32 => "gwit",
+    44504u32 => "gwik",
+    44505u32 => "gwit",
+    44506u32 => "gwip",
+    44507u32 => "gwit",
+    44508u32 => "gyu",
+    44509u32 => "gyuk",
+    44510u32 => "gyuk",
+    44511u32 => "gyuk",
+    44512u32 => "gyun",
+    44513u32 => "gyun",
+    44514u32 => "gyun",
+    44515u32 => "gyut",
+    44516u32 => "gyul",
+    44517u32 => "gyuk",
+    44518u32 => "gyum",
+    44519u32 => "gyul",
+    44520u32 => "gyul",
+    44521u32 => "gyul",
+    44522u32 => "gyup",
+    44523u32 => "gyul",
+    44524u32 => "gyum",
+    44525u32 => "gyup",
+    44526u32 => "gyup",
+    44527u32 => "gyut",
+    44528u32 => "gyut",
+    44529u32 => "gyung",
+    44530u32 => "gyut",
+    44531u32 => "gyut",
+    44532u32 => "gyuk",
+    44533u32 => "gyut",
+    44534u32 => "gyup",
+    44535u32 => "gyut",
+    44536u32 => "geu",
+    44537u32 => "geuk",
+    44538u32 => "geuk",
+    44539u32 => "geuk",
+    44540u32 => "geun",
+    44541u32 => "geun",
+    44542u32 => "geun",
+    44543u32 => "geut",
+};
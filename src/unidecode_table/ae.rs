@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_AE: phf::Map<u32, &str> = phf_map!{
+    44544u32 => "geul",
+    44545u32 => "geuk",
+    44546u32 => "geum",
+    44547u32 => "geul",
+    44548u32 => "geul",
+    44549u32 => "geul",
+    44550u32 => "geup",
+    44551u32 => "geul",
+    44552u32 => "geum",
+    44553u32 => "geup",
+    44554u32 => "geup",
+    44555u32 => "geut",
+    44556u32 => "geut",
+    44557u32 => "geung",
+    44558u32 => "geut",
+    44559u32 => "geut",
+    44560u32 => "geuk",
+    44561u32 => "geut",
+    44562u32 => "geup",
+    44563u32 => "geut",
+    44564u32 => "gui",
+    44565u32 => "guik",
+    44566u32 => "guik",
+    44567u32 => "guik",
+    44568u32 => "guin",
+    44569u32 => "guin",
+    44570u32 => "guin",
+    44571u32 => "guit",
+    44572u32 => "guil",
+    44573u32 => "guik",
+    44574u32 => "guim",
+    44575u32 => "guil",
+    44576u32 => "guil",
+    44577u32 => "guil",
+    44578u32 => "guip",
+    44579u32 => "guil",
+    44580u32 => "guim",
+    44581u32 => "guip",
+    44582u32 => "guip",
+    44583u32 => "guit",
+    44584u32 => "guit",
+    44585u32 => "guing",
+    44586u32 => "guit",
+    44587u32 => "guit",
+    44588u32 => "guik",
+    44589u32 => "guit",
+    44590u32 => "guip",
+    44591u32 => "guit",
+    44592u32 => "gi",
+    44593u32 => "gik",
+    44594u32 => "gik",
+    44595u32 => "gik",
+    44596u32 => "gin",
+    44597u32 => "gin",
+    44598u32 => "gin",
+    44599u32 => "git",
+    44600u32 => "gil",
+    44601u32 => "gik",
+    44602u32 => "gim",
+    44603u32 => "gil",
+    44604u32 => "gil",
+    44605u32 => "gil",
+    44606u32 => "gip",
+    44607u32 => "gil",
+    44608u32 => "gim",
+    44609u32 => "gip",
+    44610u32 => "gip",
+    44611u32 => "git",
+    44612u32 => "git",
+    44613u32 => "ging",
+    44614u32 => "git",
+    44615u32 => "git",
+    44616u32 => "gik",
+    44617u32 => "git",
+    44618u32 => "gip",
+    44619u32 => "git",
+    44620u32 => "kka",
+    44621u32 => "kkak",
+    44622u32 => "kkak",
+    44623u32 => "kkak",
+    44624u32 => "kkan",
+    44625u32 => "kkan",
+    44626u32 => "kkan",
+    44627u32 => "kkat",
+    44628u32 => "kkal",
+    44629u32 => "kkak",
+    44630u32 => "kkam",
+    44631u32 => "kkal",
+    44632u32 => "kkal",
+    44633u32 => "kkal",
+    44634u32 => "kkap",
+    44635u32 => "kkal",
+    44636u32 => "kkam",
+    44637u32 => "kkap",
+    44638u32 => "kkap",
+    44639u32 => "kkat",
+    44640u32 => "kkat",
+    44641u32 => "kkang",
+    44642u32 => "kkat",
+    44643u32 => "kkat",
+    44644u32 => "kkak",
+    44645u32 => "kkat",
+    44646u32 => "kkap",
+    44647u32 => "kkat",
+    44648u32 => "kkae",
+    44649u32 => "kkaek",
+    44650u32 => "kkaek",
+    44651u32 => "kkaek",
+    44652u32 => "kkaen",
+    44653u32 => "kkaen",
+    44654u32 => "kkaen",
+    44655u32 => "kkaet",
+    44656u32 => "kkael",
+    44657u32 => "kkaek",
+    44658u32 => "kkaem",
+    44659u32 => "kkael",
+    44660u32 => "kkael",
+    44661u32 => "kkael",
+    44662u32 => "kkaep",
+    44663u32 => "kkael",
+    44664u32 => "kkaem",
+    44665u32 => "kkaep",
+    44666u32 => "kkaep",
+    44667u32 => "kkaet",
+    44668u32 => "kkaet",
+    44669u32 => "kkaeng",
+    44670u32 => "kkaet",
+    44671u32 => "kkaet",
+    44672u32 => "kkaek",
+    44673u32 => "kkaet",
+    44674u32 => "kkaep",
+    44675u32 => "kkaet",
+    44676u32 => "kkya",
+    44677u32 => "kkyak",
+    44678u32 => "kkyak",
+    44679u32 => "kkyak",
+    44680u32 => "kkyan",
+    44681u32 => "kkyan",
+    44682u32 => "kkyan",
+    44683u32 => "kkyat",
+    44684u32 => "kkyal",
+    44685u32 => "kkyak",
+    44686u32 => "kkyam",
+    44687u32 => "kkyal",
+    44688u32 => "kkyal",
+    44689u32 => "kkyal",
+    44690u32 => "kkyap",
+    44691u32 => "kkyal",
+    44692u32 => "kkyam",
+    44693u32 => "kkyap",
+    44694u32 => "kkyap",
+    44695u32 => "kkyat",
+    44696u32 => "kkyat",
+    44697u32 => "kkyang",
+    44698u32 => "kkyat",
+    44699u32 => "kkyat",
+    44700u32 => "kkyak",
+    44701u32 => "kkyat",
+    44702u32 => "kkyap",
+    44703u32 => "kkyat",
+    44704u32 => "kkyae",
+    44705u32 => "kkyaek",
+    44706u32 => "kkyaek",
+    44707u32 => "kkyaek",
+    44708u32 => "kkyaen",
+    44709u32 => "kkyaen",
+    44710u32 => "kkyaen",
+    44711u32 => "kkyaet",
+    44712u32 => "kkyael",
+    44713u32 => "kkyaek",
+    44714u32 => "kkyaem",
+    44715u32 => "kkyael",
+    44716u32 => "kkyael",
+    44717u32 => "kkyael",
+    44718u32 => "kkyaep",
+    44719u32 => "kkyael",
+    44720u32 => "kkyaem",
+    44721u32 => "kkyaep",
+    44722u32 => "kkyaep",
+    44723u32 => "kkyaet",
+    44724u32 => "kkyaet",
+    44725u32 => "kkyaeng",
+    44726u32 => "kkyaet",
+    44727u32 => "kkyaet",
+    44728u32 => "kkyaek",
+    44729u32 => "kkyaet",
+    44730u32 => "kkyaep",
+    44731u32 => "kkyaet",
+    44732u32 => "kkeo",
+    44733u32 => "kkeok",
+    44734u32 => "kkeok",
+    44735u32 => "kkeok",
+    44736u32 => "kkeon",
+    44737u32 => "kkeon",
+    44738u32 => "kkeon",
+    44739u32 => "kkeot",
+    44740u32 => "kkeol",
+    44741u32 => "kkeok",
+    44742u32 => "kkeom",
+    44743u32 => "kkeol",
+    44744u32 => "kkeol",
+    44745u32 => "kkeol",
+    44746u32 => "kkeop",
+    44747u32 => "kkeol",
+    44748u32 => "kkeom",
+    44749u32 => "kkeop",
+    44750u32 => "kkeop",
+    44751u32 => "kkeot",
+    44752u32 => "kkeot",
+    44753u32 => "kkeong",
+    44754u32 => "kkeot",
+    44755u32 => "kkeot",
+    44756u32 => "kkeok",
+    44757u32 => "kkeot",
+    44758u32 => "kkeop",
+    44759u32 => "kkeot",
+    44760u32 => "kke",
+    44761u32 => "kkek",
+    44762u32 => "kkek",
+    44763u32 => "kkek",
+    44764u32 => "kken",
+    44765u32 => "kken",
+    44766u32 => "kken",
+    44767u32 => "kket",
+    44768u32 => "kkel",
+    44769u32 => "kkek",
+    44770u32 => "kkem",
+    44771u32 => "kkel",
+    44772u32 => "kkel",
+    44773u32 => "kkel",
+    44774u32 => "kkep",
+    44775u32 => "kkel",
+    44776u32 => "kkem",
+    44777u32 => "kkep",
+    44778u32 => "kkep",
+    44779u32 => "kket",
+    44780u32 => "kket",
+    44781u32 => "kkeng",
+    44782u32 => "kket",
+    44783u32 => "kket",
+    44784u32 => "kkek",
+    44785u32 => "kket",
+    44786u32 => "kkep",
+    44787u32 => "kket",
+    44788u32 => "kkyeo",
+    44789u32 => "kkyeok",
+    44790u32 => "kkyeok",
+    44791u32 => "kkyeok",
+    44792u32 => "kkyeon",
+    44793u32 => "kkyeon",
+    44794u32 => "kkyeon",
+    44795u32 => "kkyeot",
+    44796u32 => "kkyeol",
+    44797u32 => "kkyeok",
+    44798u32 => "kkyeom",
+    44799u32 => "kkyeol",
+};
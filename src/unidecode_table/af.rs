@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_AF: phf::Map<u32, &str> = phf_map!{
+    44800u32 => "kkyeol",
+    44801u32 => "kkyeol",
+    44802u32 => "kkyeop",
+    44803u32 => "kkyeol",
+    44804u32 => "kkyeom",
+    44805u32 => "kkyeop",
+    44806u32 => "kkyeop",
+    44807u32 => "kkyeot",
+    44808u32 => "kkyeot",
+    44809u32 => "kkyeong",
+    44810u32 => "kkyeot",
+    44811u32 => "kkyeot",
+    44812u32 => "kkyeok",
+    44813u32 => "kkyeot",
+    44814u32 => "kkyeop",
+    44815u32 => "kkyeot",
+    44816u32 => "kkye",
+    44817u32 => "kkyek",
+    44818u32 => "kkyek",
+    44819u32 => "kkyek",
+    44820u32 => "kkyen",
+    44821u32 => "kkyen",
+    44822u32 => "kkyen",
+    44823u32 => "kkyet",
+    44824u32 => "kkyel",
+    44825u32 => "kkyek",
+    44826u32 => "kkyem",
+    44827u32 => "kkyel",
+    44828u32 => "kkyel",
+    44829u32 => "kkyel",
+    44830u32 => "kkyep",
+    44831u32 => "kkyel",
+    44832u32 => "kkyem",
+    44833u32 => "kkyep",
+    44834u32 => "kkyep",
+    44835u32 => "kkyet",
+    44836u32 => "kkyet",
+    44837u32 => "kkyeng",
+    44838u32 => "kkyet",
+    44839u32 => "kkyet",
+    44840u32 => "kkyek",
+    44841u32 => "kkyet",
+    44842u32 => "kkyep",
+    44843u32 => "kkyet",
+    44844u32 => "kko",
+    44845u32 => "kkok",
+    44846u32 => "kkok",
+    44847u32 => "kkok",
+    44848u32 => "kkon",
+    44849u32 => "kkon",
+    44850u32 => "kkon",
+    44851u32 => "kkot",
+    44852u32 => "kkol",
+    44853u32 => "kkok",
+    44854u32 => "kkom",
+    44855u32 => "kkol",
+    44856u32 => "kkol",
+    44857u32 => "kkol",
+    44858u32 => "kkop",
+    44859u32 => "kkol",
+    44860u32 => "kkom",
+    44861u32 => "kkop",
+    44862u32 => "kkop",
+    44863u32 => "kkot",
+    44864u32 => "kkot",
+    44865u32 => "kkong",
+    44866u32 => "kkot",
+    44867u32 => "kkot",
+    44868u32 => "kkok",
+    44869u32 => "kkot",
+    44870u32 => "kkop",
+    44871u32 => "kkot",
+    44872u32 => "kkwa",
+    44873u32 => "kkwak",
+    44874u32 => "kkwak",
+    44875u32 => "kkwak",
+    44876u32 => "kkwan",
+    44877u32 => "kkwan",
+    44878u32 => "kkwan",
+    44879u32 => "kkwat",
+    44880u32 => "kkwal",
+    44881u32 => "kkwak",
+    44882u32 => "kkwam",
+    44883u32 => "kkwal",
+    44884u32 => "kkwal",
+    44885u32 => "kkwal",
+    44886u32 => "kkwap",
+    44887u32 => "kkwal",
+    44888u32 => "kkwam",
+    44889u32 => "kkwap",
+    44890u32 => "kkwap",
+    44891u32 => "kkwat",
+    44892u32 => "kkwat",
+    44893u32 => "kkwang",
+    44894u32 => "kkwat",
+    44895u32 => "kkwat",
+    44896u32 => "kkwak",
+    44897u32 => "kkwat",
+    44898u32 => "kkwap",
+    44899u32 => "kkwat",
+    44900u32 => "kkwae",
+    44901u32 => "kkwaek",
+    44902u32 => "kkwaek",
+    44903u32 => "kkwaek",
+    44904u32 => "kkwaen",
+    44905u32 => "kkwaen",
+    44906u32 => "kkwaen",
+    44907u32 => "kkwaet",
+    44908u32 => "kkwael",
+    44909u32 => "kkwaek",
+    44910u32 => "kkwaem",
+    44911u32 => "kkwael",
+    44912u32 => "kkwael",
+    44913u32 => "kkwael",
+    44914u32 => "kkwaep",
+    44915u32 => "kkwael",
+    44916u32 => "kkwaem",
+    44917u32 => "kkwaep",
+    44918u32 => "kkwaep",
+    44919u32 => "kkwaet",
+    44920u32 => "kkwaet",
+    44921u32 => "kkwaeng",
+    44922u32 => "kkwaet",
+    44923u32 => "kkwaet",
+    44924u32 => "kkwaek",
+    44925u32 => "kkwaet",
+    44926u32 => "kkwaep",
+    44927u32 => "kkwaet",
+    44928u32 => "kkoe",
+    44929u32 => "kkoek",
+    44930u32 => "kkoek",
+    44931u32 => "kkoek",
+    44932u32 => "kkoen",
+    44933u32 => "kkoen",
+    44934u32 => "kkoen",
+    44935u32 => "kkoet",
+    44936u32 => "kkoel",
+    44937u32 => "kkoek",
+    44938u32 => "kkoem",
+    44939u32 => "kkoel",
+    44940u32 => "kkoel",
+    44941u32 => "kkoel",
+    44942u32 => "kkoep",
+    44943u32 => "kkoel",
+    44944u32 => "kkoem",
+    44945u32 => "kkoep",
+    44946u32 => "kkoep",
+    44947u32 => "kkoet",
+    44948u32 => "kkoet",
+    44949u32 => "kkoeng",
+    44950u32 => "kkoet",
+    44951u32 => "kkoet",
+    44952u32 => "kkoek",
+    44953u32 => "kkoet",
+    44954u32 => "kkoep",
+    44955u32 => "kkoet",
+    44956u32 => "kkyo",
+    44957u32 => "kkyok",
+    44958u32 => "kkyok",
+    44959u32 => "kkyok",
+    44960u32 => "kkyon",
+    44961u32 => "kkyon",
+    44962u32 => "kkyon",
+    44963u32 => "kkyot",
+    44964u32 => "kkyol",
+    44965u32 => "kkyok",
+    44966u32 => "kkyom",
+    44967u32 => "kkyol",
+    44968u32 => "kkyol",
+    44969u32 => "kkyol",
+    44970u32 => "kkyop",
+    44971u32 => "kkyol",
+    44972u32 => "kkyom",
+    44973u32 => "kkyop",
+    44974u32 => "kkyop",
+    44975u32 => "kkyot",
+    44976u32 => "kkyot",
+    44977u32 => "kkyong",
+    44978u32 => "kkyot",
+    44979u32 => "kkyot",
+    44980u32 => "kkyok",
+    44981u32 => "kkyot",
+    44982u32 => "kkyop",
+    44983u32 => "kkyot",
+    44984u32 => "kku",
+    44985u32 => "kkuk",
+    44986u32 => "kkuk",
+    44987u32 => "kkuk",
+    44988u32 => "kkun",
+    44989u32 => "kkun",
+    44990u32 => "kkun",
+    44991u32 => "kkut",
+    44992u32 => "kkul",
+    44993u32 => "kkuk",
+    44994u32 => "kkum",
+    44995u32 => "kkul",
+    44996u32 => "kkul",
+    44997u32 => "kkul",
+    44998u32 => "kkup",
+    44999u32 => "kkul",
+    45000u32 => "kkum",
+    45001u32 => "kkup",
+    45002u32 => "kkup",
+    45003u32 => "kkut",
+    45004u32 => "kkut",
+    45005u32 => "kkung",
+    45006u32 => "kkut",
+    45007u32 => "kkut",
+    45008u32 => "kkuk",
+    45009u32 => "kkut",
+    45010u32 => "kkup",
+    45011u32 => "kkut",
+    45012u32 => "kkwo",
+    45013u32 => "kkwok",
+    45014u32 => "kkwok",
+    45015u32 => "kkwok",
+    45016u32 => "kkwon",
+    45017u32 => "kkwon",
+    45018u32 => "kkwon",
+    45019u32 => "kkwot",
+    45020u32 => "kkwol",
+    45021u32 => "kkwok",
+    45022u32 => "kkwom",
+    45023u32 => "kkwol",
+    45024u32 => "kkwol",
+    45025u32 => "kkwol",
+    45026u32 => "kkwop",
+    45027u32 => "kkwol",
+    45028u32 => "kkwom",
+    45029u32 => "kkwop",
+    45030u32 => "kkwop",
+    45031u32 => "kkwot",
+    45032u32 => "kkwot",
+    45033u32 => "kkwong",
+    45034u32 => "kkwot",
+    45035u32 => "kkwot",
+    45036u32 => "kkwok",
+    45037u32 => "kkwot",
+    45038u32 => "kkwop",
+    45039u32 => "kkwot",
+    45040u32 => "kkwe",
+    45041u32 => "kkwek",
+    45042u32 => "kkwek",
+    45043u32 => "kkwek",
+    45044u32 => "kkwen",
+    45045u32 => "kkwen",
+    45046u32 => "kkwen",
+    45047u32 => "kkwet",
+    45048u32 => "kkwel",
+    45049u32 => "kkwek",
+    45050u32 => "kkwem",
+    45051u32 => "kkwel",
+    45052u32 => "kkwel",
+    45053u32 => "kkwel",
+    45054u32 => "kkwep",
+    45055u32 => "kkwel",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B0: phf::Map<u32, &str> = phf_map!{
+    45056u32 => "kkwem",
+    45057u32 => "kkwep",
+    45058u32 => "kkwep",
+    45059u32 => "kkwet",
+    45060u32 => "kkwet",
+    45061u32 => "kkweng",
+    45062u32 => "kkwet",
+    45063u32 => "kkwet",
+    45064u32 => "kkwek",
+    45065u32 => "kkwet",
+    45066u32 => "kkwep",
+    45067u32 => "kkwet",
+    45068u32 => "kkwi",
+    45069u32 => "kkwik",
+    45070u32 => "kkwik",
+    45071u32 => "kkwik",
+    45072u32 => "kkwin",
+    45073u32 => "kkwin",
+    45074u32 => "kkwin",
+    45075u32 => "kkwit",
+    45076u32 => "kkwil",
+    45077u32 => "kkwik",
+    45078u32 => "kkwim",
+    45079u32 => "kkwil",
+    45080u32 => "kkwil",
+    45081u32 => "kkwil",
+    45082u32 => "kkwip",
+    45083u32 => "kkwil",
+    45084u32 => "kkwim",
+    45085u32 => "kkwip",
+    45086u32 => "kkwip",
+    45087u32 => "kkwit",
+    45088u32 => "kkwit",
+    45089u32 => "kkwing",
+    45090u32 => "kkwit",
+    45091u32 => "kkwit",
+    45092u32 => "kkwik",
+    45093u32 => "kkwit",
+    45094u32 => "kkwip",
+    45095u32 => "kkwit",
+    45096u32 => "kkyu",
+    45097u32 => "kkyuk",
+    45098u32 => "kkyuk",
+    45099u32 => "kkyuk",
+    45100u32 => "kkyun",
+    45101u32 => "kkyun",
+    45102u32 => "kkyun",
+    45103u32 => "kkyut",
+    45104u32 => "kkyul",
+    45105u32 => "kkyuk",
+    45106u32 => "kkyum",
+    45107u32 => "kkyul",
+    45108u32 => "kkyul",
+    45109u32 => "kkyul",
+    45110u32 => "kkyup",
+    45111u32 => "kkyul",
+    45112u32 => "kkyum",
+    45113u32 => "kkyup",
+    45114u32 => "kkyup",
+    45115u32 => "kkyut",
+    45116u32 => "kkyut",
+    45117u32 => "kkyung",
+    45118u32 => "kkyut",
+    45119u32 => "kkyut",
+    45120u32 => "kkyuk",
+    45121u32 => "kkyut",
+    45122u32 => "kkyup",
+    45123u32 => "kkyut",
+    45124u32 => "kkeu",
+    45125u32 => "kkeuk",
+    45126u32 => "kkeuk",
+    45127u32 => "kkeuk",
+    45128u32 => "kkeun",
+    45129u32 => "kkeun",
+    45130u32 => "kkeun",
+    45131u32 => "kkeut",
+    45132u32 => "kkeul",
+    45133u32 => "kkeuk",
+    45134u32 => "kkeum",
+    45135u32 => "kkeul",
+    45136u32 => "kkeul",
+    45137u32 => "kkeul",
+    45138u32 => "kkeup",
+    45139u32 => "kkeul",
+    45140u32 => "kkeum",
+    45141u32 => "kkeup",
+    45142u32 => "kkeup",
+    45143u32 => "kkeut",
+    45144u32 => "kkeut",
+    45145u32 => "kkeung",
+    45146u32 => "kkeut",
+    45147u32 => "kkeut",
+    45148u32 => "kkeuk",
+    45149u32 => "kkeut",
+    45150u32 => "kkeup",
+    45151u32 => "kkeut",
+    45152u32 => "kkui",
+    45153u32 => "kkuik",
+    45154u32 => "kkuik",
+    45155u32 => "kkuik",
+    45156u32 => "kkuin",
+    45157u32 => "kkuin",
+    45158u32 => "kkuin",
+    45159u32 => "kkuit",
+    45160u32 => "kkuil",
+    45161u32 => "kkuik",
+    45162u32 => "kkuim",
+    45163u32 => "kkuil",
+    45164u32 => "kkuil",
+    45165u32 => "kkuil",
+    45166u32 => "kkuip",
+    45167u32 => "kkuil",
+    45168u32 => "kkuim",
+    45169u32 => "kkuip",
+    45170u32 => "kkuip",
+    45171u32 => "kkuit",
+    45172u32 => "kkuit",
+    45173u32 => "kkuing",
+    45174u32 => "kkuit",
+    45175u32 => "kkuit",
+    45176u32 => "kkuik",
+    45177u32 => "kkuit",
+    45178u32 => "kkuip",
+    45179u32 => "kkuit",
+    45180u32 => "kki",
+    45181u32 => "kkik",
+    45182u32 => "kkik",
+    45183u32 => "kkik",
+    45184u32 => "kkin",
+    45185u32 => "kkin",
+    45186u32 => "kkin",
+    45187u32 => "kkit",
+    45188u32 => "kkil",
+    45189u32 => "kkik",
+    45190u32 => "kkim",
+    45191u32 => "kkil",
+    45192u32 => "kkil",
+    45193u32 => "kkil",
+    45194u32 => "kkip",
+    45195u32 => "kkil",
+    45196u32 => "kkim",
+    45197u32 => "kkip",
+    45198u32 => "kkip",
+    45199u32 => "kkit",
+    45200u32 => "kkit",
+    45201u32 => "kking",
+    45202u32 => "kkit",
+    45203u32 => "kkit",
+    45204u32 => "kkik",
+    45205u32 => "kkit",
+    45206u32 => "kkip",
+    45207u32 => "kkit",
+    45208u32 => "na",
+    45209u32 => "nak",
+    45210u32 => "nak",
+    45211u32 => "nak",
+    45212u32 => "nan",
+    45213u32 => "nan",
+    45214u32 => "nan",
+    45215u32 => "nat",
+    45216u32 => "nal",
+    45217u32 => "nak",
+    45218u32 => "nam",
+    45219u32 => "nal",
+    45220u32 => "nal",
+    45221u32 => "nal",
+    45222u32 => "nap",
+    45223u32 => "nal",
+    45224u32 => "nam",
+    45225u32 => "nap",
+    45226u32 => "nap",
+    45227u32 => "nat",
+    45228u32 => "nat",
+    45229u32 => "nang",
+    45230u32 => "nat",
+    45231u32 => "nat",
+    45232u32 => "nak",
+    45233u32 => "nat",
+    45234u32 => "nap",
+    45235u32 => "nat",
+    45236u32 => "nae",
+    45237u32 => "naek",
+    45238u32 => "naek",
+    45239u32 => "naek",
+    45240u32 => "naen",
+    45241u32 => "naen",
+    45242u32 => "naen",
+    45243u32 => "naet",
+    45244u32 => "nael",
+    45245u32 => "naek",
+    45246u32 => "naem",
+    45247u32 => "nael",
+    45248u32 => "nael",
+    45249u32 => "nael",
+    45250u32 => "naep",
+    45251u32 => "nael",
+    45252u32 => "naem",
+    45253u32 => "naep",
+    45254u32 => "naep",
+    45255u32 => "naet",
+    45256u32 => "naet",
+    45257u32 => "naeng",
+    45258u32 => "naet",
+    45259u32 => "naet",
+    45260u32 => "naek",
+    45261u32 => "naet",
+    45262u32 => "naep",
+    45263u32 => "naet",
+    45264u32 => "nya",
+    45265u32 => "nyak",
+    45266u32 => "nyak",
+    45267u32 => "nyak",
+    45268u32 => "nyan",
+    45269u32 => "nyan",
+    45270u32 => "nyan",
+    45271u32 => "nyat",
+    45272u32 => "nyal",
+    45273u32 => "nyak",
+    45274u32 => "nyam",
+    45275u32 => "nyal",
+    45276u32 => "nyal",
+    45277u32 => "nyal",
+    45278u32 => "nyap",
+    45279u32 => "nyal",
+    45280u32 => "nyam",
+    45281u32 => "nyap",
+    45282u32 => "nyap",
+    45283u32 => "nyat",
+    45284u32 => "nyat",
+    45285u32 => "nyang",
+    45286u32 => "nyat",
+    45287u32 => "nyat",
+    45288u32 => "nyak",
+    45289u32 => "nyat",
+    45290u32 => "nyap",
+    45291u32 => "nyat",
+    45292u32 => "nyae",
+    45293u32 => "nyaek",
+    45294u32 => "nyaek",
+    45295u32 => "nyaek",
+    45296u32 => "nyaen",
+    45297u32 => "nyaen",
+    45298u32 => "nyaen",
+    45299u32 => "nyaet",
+    45300u32 => "nyael",
+    45301u32 => "nyaek",
+    45302u32 => "nyaem",
+    45303u32 => "nyael",
+    45304u32 => "nyael",
+    45305u32 => "nyael",
+    45306u32 => "nyaep",
+    45307u32 => "nyael",
+    45308u32 => "nyaem",
+    45309u32 => "nyaep",
+    45310u32 => "nyaep",
+    45311u32 => "nyaet",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B1: phf::Map<u32, &str> = phf_map!{
+    45312u32 => "nyaet",
+    45313u32 => "nyaeng",
+    45314u32 => "nyaet",
+    45315u32 => "nyaet",
+    45316u32 => "nyaek",
+    45317u32 => "nyaet",
+    45318u32 => "nyaep",
+    45319u32 => "nyaet",
+    45320u32 => "neo",
+    45321u32 => "neok",
+    45322u32 => "neok",
+    45323u32 => "neok",
+    45324u32 => "neon",
+    45325u32 => "neon",
+    45326u32 => "neon",
+    45327u32 => "neot",
+    45328u32 => "neol",
+    45329u32 => "neok",
+    45330u32 => "neom",
+    45331u32 => "neol",
+    45332u32 => "neol",
+    45333u32 => "neol",
+    45334u32 => "neop",
+    45335u32 => "neol",
+    45336u32 => "neom",
+    45337u32 => "neop",
+    45338u32 => "neop",
+    45339u32 => "neot",
+    45340u32 => "neot",
+    45341u32 => "neong",
+    45342u32 => "neot",
+    45343u32 => "neot",
+    45344u32 => "neok",
+    45345u32 => "neot",
+    45346u32 => "neop",
+    45347u32 => "neot",
+    45348u32 => "ne",
+    45349u32 => "nek",
+    45350u32 => "nek",
+    45351u32 => "nek",
+    45352u32 => "nen",
+    45353u32 => "nen",
+    45354u32 => "nen",
+    45355u32 => "net",
+    45356u32 => "nel",
+    45357u32 => "nek",
+    45358u32 => "nem",
+    45359u32 => "nel",
+    45360u32 => "nel",
+    45361u32 => "nel",
+    45362u32 => "nep",
+    45363u32 => "nel",
+    45364u32 => "nem",
+    45365u32 => "nep",
+    45366u32 => "nep",
+    45367u32 => "net",
+    45368u32 => "net",
+    45369u32 => "neng",
+    45370u32 => "net",
+    45371u32 => "net",
+    45372u32 => "nek",
+    45373u32 => "net",
+    45374u32 => "nep",
+    45375u32 => "net",
+    45376u32 => "nyeo",
+    45377u32 => "nyeok",
+    45378u32 => "nyeok",
+    45379u32 => "nyeok",
+    45380u32 => "nyeon",
+    45381u32 => "nyeon",
+    45382u32 => "nyeon",
+    45383u32 => "nyeot",
+    45384u32 => "nyeol",
+    45385u32 => "nyeok",
+    45386u32 => "nyeom",
+    45387u32 => "nyeol",
+    45388u32 => "nyeol",
+    45389u32 => "nyeol",
+    45390u32 => "nyeop",
+    45391u32 => "nyeol",
+    45392u32 => "nyeom",
+    45393u32 => "nyeop",
+    45394u32 => "nyeop",
+    45395u32 => "nyeot",
+    45396u32 => "nyeot",
+    45397u32 => "nyeong",
+    45398u32 => "nyeot",
+    45399u32 => "nyeot",
+    45400u32 => "nyeok",
+    45401u32 => "nyeot",
+    45402u32 => "nyeop",
+    45403u32 => "nyeot",
+    45404u32 => "nye",
+    45405u32 => "nyek",
+    45406u32 => "nyek",
+    45407u32 => "nyek",
+    45408u32 => "nyen",
+    45409u32 => "nyen",
+    45410u32 => "nyen",
+    45411u32 => "nyet",
+    45412u32 => "nyel",
+    45413u32 => "nyek",
+    45414u32 => "nyem",
+    45415u32 => "nyel",
+    45416u32 => "nyel",
+    45417u32 => "nyel",
+    45418u32 => "nyep",
+    45419u32 => "nyel",
+    45420u32 => "nyem",
+    45421u32 => "nyep",
+    45422u32 => "nyep",
+    45423u32 => "nyet",
+    45424u32 => "nyet",
+    45425u32 => "nyeng",
+    45426u32 => "nyet",
+    45427u32 => "nyet",
+    45428u32 => "nyek",
+    45429u32 => "nyet",
+    45430u32 => "nyep",
+    45431u32 => "nyet",
+    45432u32 => "no",
+    45433u32 => "nok",
+    45434u32 => "nok",
+    45435u32 => "nok",
+    45436u32 => "non",
+    45437u32 => "non",
+    45438u32 => "non",
+    45439u32 => "not",
+    45440u32 => "nol",
+    45441u32 => "nok",
+    45442u32 => "nom",
+    45443u32 => "nol",
+    45444u32 => "nol",
+    45445u32 => "nol",
+    45446u32 => "nop",
+    45447u32 => "nol",
+    45448u32 => "nom",
+    45449u32 => "nop",
+    45450u32 => "nop",
+    45451u32 => "not",
+    45452u32 => "not",
+    45453u32 => "nong",
+    45454u32 => "not",
+    45455u32 => "not",
+    45456u32 => "nok",
+    45457u32 => "not",
+    45458u32 => "nop",
+    45459u32 => "not",
+    45460u32 => "nwa",
+    45461u32 => "nwak",
+    45462u32 => "nwak",
+    45463u32 => "nwak",
+    45464u32 => "nwan",
+    45465u32 => "nwan",
+    45466u32 => "nwan",
+    45467u32 => "nwat",
+    45468u32 => "nwal",
+    45469u32 => "nwak",
+    45470u32 => "nwam",
+    45471u32 => "nwal",
+    45472u32 => "nwal",
+    45473u32 => "nwal",
+    45474u32 => "nwap",
+    45475u32 => "nwal",
+    45476u32 => "nwam",
+    45477u32 => "nwap",
+    45478u32 => "nwap",
+    45479u32 => "nwat",
+    45480u32 => "nwat",
+    45481u32 => "nwang",
+    45482u32 => "nwat",
+    45483u32 => "nwat",
+    45484u32 => "nwak",
+    45485u32 => "nwat",
+    45486u32 => "nwap",
+    45487u32 => "nwat",
+    45488u32 => "nwae",
+    45489u32 => "nwaek",
+    45490u32 => "nwaek",
+    45491u32 => "nwaek",
+    45492u32 => "nwaen",
+    45493u32 => "nwaen",
+    45494u32 => "nwaen",
+    45495u32 => "nwaet",
+    45496u32 => "nwael",
+    45497u32 => "nwaek",
+    45498u32 => "nwaem",
+    45499u32 => "nwael",
+    45500u32 => "nwael",
+    45501u32 => "nwael",
+    45502u32 => "nwaep",
+    45503u32 => "nwael",
+    45504u32 => "nwaem",
+    45505u32 => "nwaep",
+    45506u32 => "nwaep",
+    45507u32 => "nwaet",
+    45508u32 => "nwaet",
+    45509u32 => "nwaeng",
+    45510u32 => "nwaet",
+    45511u32 => "nwaet",
+    45512u32 => "nwaek",
+    45513u32 => "nwaet",
+    45514u32 => "nwaep",
+    45515u32 => "nwaet",
+    45516u32 => "noe",
+    45517u32 => "noek",
+    45518u32 => "noek",
+    45519u32 => "noek",
+    45520u32 => "noen",
+    45521u32 => "noen",
+    45522u32 => "noen",
+    45523u32 => "noet",
+    45524u32 => "noel",
+    45525u32 => "noek",
+    45526u32 => "noem",
+    45527u32 => "noel",
+    45528u32 => "noel",
+    45529u32 => "noel",
+    45530u32 => "noep",
+    45531u32 => "noel",
+    45532u32 => "noem",
+    45533u32 => "noep",
+    45534u32 => "noep",
+    45535u32 => "noet",
+    45536u32 => "noet",
+    45537u32 => "noeng",
+    45538u32 => "noet",
+    45539u32 => "noet",
+    45540u32 => "noek",
+    45541u32 => "noet",
+    45542u32 => "noep",
+    45543u32 => "noet",
+    45544u32 => "nyo",
+    45545u32 => "nyok",
+    45546u32 => "nyok",
+    45547u32 => "nyok",
+    45548u32 => "nyon",
+    45549u32 => "nyon",
+    45550u32 => "nyon",
+    45551u32 => "nyot",
+    45552u32 => "nyol",
+    45553u32 => "nyok",
+    45554u32 => "nyom",
+    45555u32 => "nyol",
+    45556u32 => "nyol",
+    45557u32 => "nyol",
+    45558u32 => "nyop",
+    45559u32 => "nyol",
+    45560u32 => "nyom",
+    45561u32 => "nyop",
+    45562u32 => "nyop",
+    45563u32 => "nyot",
+    45564u32 => "nyot",
+    45565u32 => "nyong",
+    45566u32 => "nyot",
+    45567u32 => "nyot",
+};
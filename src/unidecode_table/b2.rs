@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B2: phf::Map<u32, &str> = phf_map!{
+    45568u32 => "nyok",
+    45569u32 => "nyot",
+    45570u32 => "nyop",
+    45571u32 => "nyot",
+    45572u32 => "nu",
+    45573u32 => "nuk",
+    45574u32 => "nuk",
+    45575u32 => "nuk",
+    45576u32 => "nun",
+    45577u32 => "nun",
+    45578u32 => "nun",
+    45579u32 => "nut",
+    45580u32 => "nul",
+    45581u32 => "nuk",
+    45582u32 => "num",
+    45583u32 => "nul",
+    45584u32 => "nul",
+    45585u32 => "nul",
+    45586u32 => "nup",
+    45587u32 => "nul",
+    45588u32 => "num",
+    45589u32 => "nup",
+    45590u32 => "nup",
+    45591u32 => "nut",
+    45592u32 => "nut",
+    45593u32 => "nung",
+    45594u32 => "nut",
+    45595u32 => "nut",
+    45596u32 => "nuk",
+    45597u32 => "nut",
+    45598u32 => "nup",
+    45599u32 => "nut",
+    45600u32 => "nwo",
+    45601u32 => "nwok",
+    45602u32 => "nwok",
+    45603u32 => "nwok",
+    45604u32 => "nwon",
+    45605u32 => "nwon",
+    45606u32 => "nwon",
+    45607u32 => "nwot",
+    45608u32 => "nwol",
+    45609u32 => "nwok",
+    45610u32 => "nwom",
+    45611u32 => "nwol",
+    45612u32 => "nwol",
+    45613u32 => "nwol",
+    45614u32 => "nwop",
+    45615u32 => "nwol",
+    45616u32 => "nwom",
+    45617u32 => "nwop",
+    45618u32 => "nwop",
+    45619u32 => "nwot",
+    45620u32 => "nwot",
+    45621u32 => "nwong",
+    45622u32 => "nwot",
+    45623u32 => "nwot",
+    45624u32 => "nwok",
+    45625u32 => "nwot",
+    45626u32 => "nwop",
+    45627u32 => "nwot",
+    45628u32 => "nwe",
+    45629u32 => "nwek",
+    45630u32 => "nwek",
+    45631u32 => "nwek",
+    45632u32 => "nwen",
+    45633u32 => "nwen",
+    45634u32 => "nwen",
+    45635u32 => "nwet",
+    45636u32 => "nwel",
+    45637u32 => "nwek",
+    45638u32 => "nwem",
+    45639u32 => "nwel",
+    45640u32 => "nwel",
+    45641u32 => "nwel",
+    45642u32 => "nwep",
+    45643u32 => "nwel",
+    45644u32 => "nwem",
+    45645u32 => "nwep",
+    45646u32 => "nwep",
+    45647u32 => "nwet",
+    45648u32 => "nwet",
+    45649u32 => "nweng",
+    45650u32 => "nwet",
+    45651u32 => "nwet",
+    45652u32 => "nwek",
+    45653u32 => "nwet",
+    45654u32 => "nwep",
+    45655u32 => "nwet",
+    45656u32 => "nwi",
+    45657u32 => "nwik",
+    45658u32 => "nwik",
+    45659u32 => "nwik",
+    45660u32 => "nwin",
+    45661u32 => "nwin",
+    45662u32 => "nwin",
+    45663u32 => "nwit",
+    45664u32 => "nwil",
+    45665u32 => "nwik",
+    45666u32 => "nwim",
+    45667u32 => "nwil",
+    45668u32 => "nwil",
+    45669u32 => "nwil",
+    45670u32 => "nwip",
+    45671u32 => "nwil",
+    45672u32 => "nwim",
+    45673u32 => "nwip",
+    45674u32 => "nwip",
+    45675u32 => "nwit",
+    45676u32 => "nwit",
+    45677u32 => "nwing",
+    45678u32 => "nwit",
+    45679u32 => "nwit",
+    45680u32 => "nwik",
+    45681u32 => "nwit",
+    45682u32 => "nwip",
+    45683u32 => "nwit",
+    45684u32 => "nyu",
+    45685u32 => "nyuk",
+    45686u32 => "nyuk",
+    45687u32 => "nyuk",
+    45688u32 => "nyun",
+    45689u32 => "nyun",
+    45690u32 => "nyun",
+    45691u32 => "nyut",
+    45692u32 => "nyul",
+    45693u32 => "nyuk",
+    45694u32 => "nyum",
+    45695u32 => "nyul",
+    45696u32 => "nyul",
+    45697u32 => "nyul",
+    45698u32 => "nyup",
+    45699u32 => "nyul",
+    45700u32 => "nyum",
+    45701u32 => "nyup",
+    45702u32 => "nyup",
+    45703u32 => "nyut",
+    45704u32 => "nyut",
+    45705u32 => "nyung",
+    45706u32 => "nyut",
+    45707u32 => "nyut",
+    45708u32 => "nyuk",
+    45709u32 => "nyut",
+    45710u32 => "nyup",
+    45711u32 => "nyut",
+    45712u32 => "neu",
+    45713u32 => "neuk",
+    45714u32 => "neuk",
+    45715u32 => "neuk",
+    45716u32 => "neun",
+    45717u32 => "neun",
+    45718u32 => "neun",
+    45719u32 => "neut",
+    45720u32 => "neul",
+    45721u32 => "neuk",
+    45722u32 => "neum",
+    45723u32 => "neul",
+    45724u32 => "neul",
+    45725u32 => "neul",
+    45726u32 => "neup",
+    45727u32 => "neul",
+    45728u32 => "neum",
+    45729u32 => "neup",
+    45730u32 => "neup",
+    45731u32 => "neut",
+    45732u32 => "neut",
+    45733u32 => "neung",
+    45734u32 => "neut",
+    45735u32 => "neut",
+    45736u32 => "neuk",
+    45737u32 => "neut",
+    45738u32 => "neup",
+    45739u32 => "neut",
+    45740u32 => "nui",
+    45741u32 => "nuik",
+    45742u32 => "nuik",
+    45743u32 => "nuik",
+    45744u32 => "nuin",
+    45745u32 => "nuin",
+    45746u32 => "nuin",
+    45747u32 => "nuit",
+    45748u32 => "nuil",
+    45749u32 => "nuik",
+    45750u32 => "nuim",
+    45751u32 => "nuil",
+    45752u32 => "nuil",
+    45753u32 => "nuil",
+    45754u32 => "nuip",
+    45755u32 => "nuil",
+    45756u32 => "nuim",
+    45757u32 => "nuip",
+    45758u32 => "nuip",
+    45759u32 => "nuit",
+    45760u32 => "nuit",
+    45761u32 => "nuing",
+    45762u32 => "nuit",
+    45763u32 => "nuit",
+    45764u32 => "nuik",
+    45765u32 => "nuit",
+    45766u32 => "nuip",
+    45767u32 => "nuit",
+    45768u32 => "ni",
+    45769u32 => "nik",
+    45770u32 => "nik",
+    45771u32 => "nik",
+    45772u32 => "nin",
+    45773u32 => "nin",
+    45774u32 => "nin",
+    45775u32 => "nit",
+    45776u32 => "nil",
+    45777u32 => "nik",
+    45778u32 => "nim",
+    45779u32 => "nil",
+    45780u32 => "nil",
+    45781u32 => "nil",
+    45782u32 => "nip",
+    45783u32 => "nil",
+    45784u32 => "nim",
+    45785u32 => "nip",
+    45786u32 => "nip",
+    45787u32 => "nit",
+    45788u32 => "nit",
+    45789u32 => "ning",
+    45790u32 => "nit",
+    45791u32 => "nit",
+    45792u32 => "nik",
+    45793u32 => "nit",
+    45794u32 => "nip",
+    45795u32 => "nit",
+    45796u32 => "da",
+    45797u32 => "dak",
+    45798u32 => "dak",
+    45799u32 => "dak",
+    45800u32 => "dan",
+    45801u32 => "dan",
+    45802u32 => "dan",
+    45803u32 => "dat",
+    45804u32 => "dal",
+    45805u32 => "dak",
+    45806u32 => "dam",
+    45807u32 => "dal",
+    45808u32 => "dal",
+    45809u32 => "dal",
+    45810u32 => "dap",
+    45811u32 => "dal",
+    45812u32 => "dam",
+    45813u32 => "dap",
+    45814u32 => "dap",
+    45815u32 => "dat",
+    45816u32 => "dat",
+    45817u32 => "dang",
+    45818u32 => "dat",
+    45819u32 => "dat",
+    45820u32 => "dak",
+    45821u32 => "dat",
+    45822u32 => "dap",
+    45823u32 => "dat",
+};
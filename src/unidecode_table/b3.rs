@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B3: phf::Map<u32, &str> = phf_map!{
+    45824u32 => "dae",
+    45825u32 => "daek",
+    45826u32 => "daek",
+    45827u32 => "daek",
+    45828u32 => "daen",
+    45829u32 => "daen",
+    45830u32 => "daen",
+    45831u32 => "daet",
+    45832u32 => "dael",
+    45833u32 => "daek",
+    45834u32 => "daem",
+    45835u32 => "dael",
+    45836u32 => "dael",
+    45837u32 => "dael",
+    45838u32 => "daep",
+    45839u32 => "dael",
+    45840u32 => "daem",
+    45841u32 => "daep",
+    45842u32 => "daep",
+    45843u32 => "daet",
+    45844u32 => "daet",
+    45845u32 => "daeng",
+    45846u32 => "daet",
+    45847u32 => "daet",
+    45848u32 => "daek",
+    45849u32 => "daet",
+    45850u32 => "daep",
+    45851u32 => "daet",
+    45852u32 => "dya",
+    45853u32 => "dyak",
+    45854u32 => "dyak",
+    45855u32 => "dyak",
+    45856u32 => "dyan",
+    45857u32 => "dyan",
+    45858u32 => "dyan",
+    45859u32 => "dyat",
+    45860u32 => "dyal",
+    45861u32 => "dyak",
+    45862u32 => "dyam",
+    45863u32 => "dyal",
+    45864u32 => "dyal",
+    45865u32 => "dyal",
+    45866u32 => "dyap",
+    45867u32 => "dyal",
+    45868u32 => "dyam",
+    45869u32 => "dyap",
+    45870u32 => "dyap",
+    45871u32 => "dyat",
+    45872u32 => "dyat",
+    45873u32 => "dyang",
+    45874u32 => "dyat",
+    45875u32 => "dyat",
+    45876u32 => "dyak",
+    45877u32 => "dyat",
+    45878u32 => "dyap",
+    45879u32 => "dyat",
+    45880u32 => "dyae",
+    45881u32 => "dyaek",
+    45882u32 => "dyaek",
+    45883u32 => "dyaek",
+    45884u32 => "dyaen",
+    45885u32 => "dyaen",
+    45886u32 => "dyaen",
+    45887u32 => "dyaet",
+    45888u32 => "dyael",
+    45889u32 => "dyaek",
+    45890u32 => "dyaem",
+    45891u32 => "dyael",
+    45892u32 => "dyael",
+    45893u32 => "dyael",
+    45894u32 => "dyaep",
+    45895u32 => "dyael",
+    45896u32 => "dyaem",
+    45897u32 => "dyaep",
+    45898u32 => "dyaep",
+    45899u32 => "dyaet",
+    45900u32 => "dyaet",
+    45901u32 => "dyaeng",
+    45902u32 => "dyaet",
+    45903u32 => "dyaet",
+    45904u32 => "dyaek",
+    45905u32 => "dyaet",
+    45906u32 => "dyaep",
+    45907u32 => "dyaet",
+    45908u32 => "deo",
+    45909u32 => "deok",
+    45910u32 => "deok",
+    45911u32 => "deok",
+    45912u32 => "deon",
+    45913u32 => "deon",
+    45914u32 => "deon",
+    45915u32 => "deot",
+    45916u32 => "deol",
+    45917u32 => "deok",
+    45918u32 => "deom",
+    45919u32 => "deol",
+    45920u32 => "deol",
+    45921u32 => "deol",
+    45922u32 => "deop",
+    45923u32 => "deol",
+    45924u32 => "deom",
+    45925u32 => "deop",
+    45926u32 => "deop",
+    45927u32 => "deot",
+    45928u32 => "deot",
+    45929u32 => "deong",
+    45930u32 => "deot",
+    45931u32 => "deot",
+    45932u32 => "deok",
+    45933u32 => "deot",
+    45934u32 => "deop",
+    45935u32 => "deot",
+    45936u32 => "de",
+    45937u32 => "dek",
+    45938u32 => "dek",
+    45939u32 => "dek",
+    45940u32 => "den",
+    45941u32 => "den",
+    45942u32 => "den",
+    45943u32 => "det",
+    45944u32 => "del",
+    45945u32 => "dek",
+    45946u32 => "dem",
+    45947u32 => "del",
+    45948u32 => "del",
+    45949u32 => "del",
+    45950u32 => "dep",
+    45951u32 => "del",
+    45952u32 => "dem",
+    45953u32 => "dep",
+    45954u32 => "dep",
+    45955u32 => "det",
+    45956u32 => "det",
+    45957u32 => "deng",
+    45958u32 => "det",
+    45959u32 => "det",
+    45960u32 => "dek",
+    45961u32 => "det",
+    45962u32 => "dep",
+    45963u32 => "det",
+    45964u32 => "dyeo",
+    45965u32 => "dyeok",
+    45966u32 => "dyeok",
+    45967u32 => "dyeok",
+    45968u32 => "dyeon",
+    45969u32 => "dyeon",
+    45970u32 => "dyeon",
+    45971u32 => "dyeot",
+    45972u32 => "dyeol",
+    45973u32 => "dyeok",
+    45974u32 => "dyeom",
+    45975u32 => "dyeol",
+    45976u32 => "dyeol",
+    45977u32 => "dyeol",
+    45978u32 => "dyeop",
+    45979u32 => "dyeol",
+    45980u32 => "dyeom",
+    45981u32 => "dyeop",
+    45982u32 => "dyeop",
+    45983u32 => "dyeot",
+    45984u32 => "dyeot",
+    45985u32 => "dyeong",
+    45986u32 => "dyeot",
+    45987u32 => "dyeot",
+    45988u32 => "dyeok",
+    45989u32 => "dyeot",
+    45990u32 => "dyeop",
+    45991u32 => "dyeot",
+    45992u32 => "dye",
+    45993u32 => "dyek",
+    45994u32 => "dyek",
+    45995u32 => "dyek",
+    45996u32 => "dyen",
+    45997u32 => "dyen",
+    45998u32 => "dyen",
+    45999u32 => "dyet",
+    46000u32 => "dyel",
+    46001u32 => "dyek",
+    46002u32 => "dyem",
+    46003u32 => "dyel",
+    46004u32 => "dyel",
+    46005u32 => "dyel",
+    46006u32 => "dyep",
+    46007u32 => "dyel",
+    46008u32 => "dyem",
+    46009u32 => "dyep",
+    46010u32 => "dyep",
+    46011u32 => "dyet",
+    46012u32 => "dyet",
+    46013u32 => "dyeng",
+    46014u32 => "dyet",
+    46015u32 => "dyet",
+    46016u32 => "dyek",
+    46017u32 => "dyet",
+    46018u32 => "dyep",
+    46019u32 => "dyet",
+    46020u32 => "do",
+    46021u32 => "dok",
+    46022u32 => "dok",
+    46023u32 => "dok",
+    46024u32 => "don",
+    46025u32 => "don",
+    46026u32 => "don",
+    46027u32 => "dot",
+    46028u32 => "dol",
+    46029u32 => "dok",
+    46030u32 => "dom",
+    46031u32 => "dol",
+    46032u32 => "dol",
+    46033u32 => "dol",
+    46034u32 => "dop",
+    46035u32 => "dol",
+    46036u32 => "dom",
+    46037u32 => "dop",
+    46038u32 => "dop",
+    46039u32 => "dot",
+    46040u32 => "dot",
+    46041u32 => "dong",
+    46042u32 => "dot",
+    46043u32 => "dot",
+    46044u32 => "dok",
+    46045u32 => "dot",
+    46046u32 => "dop",
+    46047u32 => "dot",
+    46048u32 => "dwa",
+    46049u32 => "dwak",
+    46050u32 => "dwak",
+    46051u32 => "dwak",
+    46052u32 => "dwan",
+    46053u32 => "dwan",
+    46054u32 => "dwan",
+    46055u32 => "dwat",
+    46056u32 => "dwal",
+    46057u32 => "dwak",
+    46058u32 => "dwam",
+    46059u32 => "dwal",
+    46060u32 => "dwal",
+    46061u32 => "dwal",
+    46062u32 => "dwap",
+    46063u32 => "dwal",
+    46064u32 => "dwam",
+    46065u32 => "dwap",
+    46066u32 => "dwap",
+    46067u32 => "dwat",
+    46068u32 => "dwat",
+    46069u32 => "dwang",
+    46070u32 => "dwat",
+    46071u32 => "dwat",
+    46072u32 => "dwak",
+    46073u32 => "dwat",
+    46074u32 => "dwap",
+    46075u32 => "dwat",
+    46076u32 => "dwae",
+    46077u32 => "dwaek",
+    46078u32 => "dwaek",
+    46079u32 => "dwaek",
+};
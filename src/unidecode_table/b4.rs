@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B4: phf::Map<u32, &str> = phf_map!{
+    46080u32 => "dwaen",
+    46081u32 => "dwaen",
+    46082u32 => "dwaen",
+    46083u32 => "dwaet",
+    46084u32 => "dwael",
+    46085u32 => "dwaek",
+    46086u32 => "dwaem",
+    46087u32 => "dwael",
+    46088u32 => "dwael",
+    46089u32 => "dwael",
+    46090u32 => "dwaep",
+    46091u32 => "dwael",
+    46092u32 => "dwaem",
+    46093u32 => "dwaep",
+    46094u32 => "dwaep",
+    46095u32 => "dwaet",
+    46096u32 => "dwaet",
+    46097u32 => "dwaeng",
+    46098u32 => "dwaet",
+    46099u32 => "dwaet",
+    46100u32 => "dwaek",
+    46101u32 => "dwaet",
+    46102u32 => "dwaep",
+    46103u32 => "dwaet",
+    46104u32 => "doe",
+    46105u32 => "doek",
+    46106u32 => "doek",
+    46107u32 => "doek",
+    46108u32 => "doen",
+    46109u32 => "doen",
+    46110u32 => "doen",
+    46111u32 => "doet",
+    46112u32 => "doel",
+    46113u32 => "doek",
+    46114u32 => "doem",
+    46115u32 => "doel",
+    46116u32 => "doel",
+    46117u32 => "doel",
+    46118u32 => "doep",
+    46119u32 => "doel",
+    46120u32 => "doem",
+    46121u32 => "doep",
+    46122u32 => "doep",
+    46123u32 => "doet",
+    46124u32 => "doet",
+    46125u32 => "doeng",
+    46126u32 => "doet",
+    46127u32 => "doet",
+    46128u32 => "doek",
+    46129u32 => "doet",
+    46130u32 => "doep",
+    46131u32 => "doet",
+    46132u32 => "dyo",
+    46133u32 => "dyok",
+    46134u32 => "dyok",
+    46135u32 => "dyok",
+    46136u32 => "dyon",
+    46137u32 => "dyon",
+    46138u32 => "dyon",
+    46139u32 => "dyot",
+    46140u32 => "dyol",
+    46141u32 => "dyok",
+    46142u32 => "dyom",
+    46143u32 => "dyol",
+    46144u32 => "dyol",
+    46145u32 => "dyol",
+    46146u32 => "dyop",
+    46147u32 => "dyol",
+    46148u32 => "dyom",
+    46149u32 => "dyop",
+    46150u32 => "dyop",
+    46151u32 => "dyot",
+    46152u32 => "dyot",
+    46153u32 => "dyong",
+    46154u32 => "dyot",
+    46155u32 => "dyot",
+    46156u32 => "dyok",
+    46157u32 => "dyot",
+    46158u32 => "dyop",
+    46159u32 => "dyot",
+    46160u32 => "du",
+    46161u32 => "duk",
+    46162u32 => "duk",
+    46163u32 => "duk",
+    46164u32 => "dun",
+    46165u32 => "dun",
+    46166u32 => "dun",
+    46167u32 => "dut",
+    46168u32 => "dul",
+    46169u32 => "duk",
+    46170u32 => "dum",
+    46171u32 => "dul",
+    46172u32 => "dul",
+    46173u32 => "dul",
+    46174u32 => "dup",
+    46175u32 => "dul",
+    46176u32 => "dum",
+    46177u32 => "dup",
+    46178u32 => "dup",
+    46179u32 => "dut",
+    46180u32 => "dut",
+    46181u32 => "dung",
+    46182u32 => "dut",
+    46183u32 => "dut",
+    46184u32 => "duk",
+    46185u32 => "dut",
+    46186u32 => "dup",
+    46187u32 => "dut",
+    46188u32 => "dwo",
+    46189u32 => "dwok",
+    46190u32 => "dwok",
+    46191u32 => "dwok",
+    46192u32 => "dwon",
+    46193u32 => "dwon",
+    46194u32 => "dwon",
+    46195u32 => "dwot",
+    46196u32 => "dwol",
+    46197u32 => "dwok",
+    46198u32 => "dwom",
+    46199u32 => "dwol",
+    46200u32 => "dwol",
+    46201u32 => "dwol",
+    46202u32 => "dwop",
+    46203u32 => "dwol",
+    46204u32 => "dwom",
+    46205u32 => "dwop",
+    46206u32 => "dwop",
+    46207u32 => "dwot",
+    46208u32 => "dwot",
+    46209u32 => "dwong",
+    46210u32 => "dwot",
+    46211u32 => "dwot",
+    46212u32 => "dwok",
+    46213u32 => "dwot",
+    46214u32 => "dwop",
+    46215u32 => "dwot",
+    46216u32 => "dwe",
+    46217u32 => "dwek",
+    46218u32 => "dwek",
+    46219u32 => "dwek",
+    46220u32 => "dwen",
+    46221u32 => "dwen",
+    46222u32 => "dwen",
+    46223u32 => "dwet",
+    46224u32 => "dwel",
+    46225u32 => "dwek",
+    46226u32 => "dwem",
+    46227u32 => "dwel",
+    46228u32 => "dwel",
+    46229u32 => "dwel",
+    46230u32 => "dwep",
+    46231u32 => "dwel",
+    46232u32 => "dwem",
+    46233u32 => "dwep",
+    46234u32 => "dwep",
+    46235u32 => "dwet",
+    46236u32 => "dwet",
+    46237u32 => "dweng",
+    46238u32 => "dwet",
+    46239u32 => "dwet",
+    46240u32 => "dwek",
+    46241u32 => "dwet",
+    46242u32 => "dwep",
+    46243u32 => "dwet",
+    46244u32 => "dwi",
+    46245u32 => "dwik",
+    46246u32 => "dwik",
+    46247u32 => "dwik",
+    46248u32 => "dwin",
+    46249u32 => "dwin",
+    46250u32 => "dwin",
+    46251u32 => "dwit",
+    46252u32 => "dwil",
+    46253u32 => "dwik",
+    46254u32 => "dwim",
+    46255u32 => "dwil",
+    46256u32 => "dwil",
+    46257u32 => "dwil",
+    46258u32 => "dwip",
+    46259u32 => "dwil",
+    46260u32 => "dwim",
+    46261u32 => "dwip",
+    46262u32 => "dwip",
+    46263u32 => "dwit",
+    46264u32 => "dwit",
+    46265u32 => "dwing",
+    46266u32 => "dwit",
+    46267u32 => "dwit",
+    46268u32 => "dwik",
+    46269u32 => "dwit",
+    46270u32 => "dwip",
+    46271u32 => "dwit",
+    46272u32 => "dyu",
+    46273u32 => "dyuk",
+    46274u32 => "dyuk",
+    46275u32 => "dyuk",
+    46276u32 => "dyun",
+    46277u32 => "dyun",
+    46278u32 => "dyun",
+    46279u32 => "dyut",
+    46280u32 => "dyul",
+    46281u32 => "dyuk",
+    46282u32 => "dyum",
+    46283u32 => "dyul",
+    46284u32 => "dyul",
+    46285u32 => "dyul",
+    46286u32 => "dyup",
+    46287u32 => "dyul",
+    46288u32 => "dyum",
+    46289u32 => "dyup",
+    46290u32 => "dyup",
+    46291u32 => "dyut",
+    46292u32 => "dyut",
+    46293u32 => "dyung",
+    46294u32 => "dyut",
+    46295u32 => "dyut",
+    46296u32 => "dyuk",
+    46297u32 => "dyut",
+    46298u32 => "dyup",
+    46299u32 => "dyut",
+    46300u32 => "deu",
+    46301u32 => "deuk",
+    46302u32 => "deuk",
+    46303u32 => "deuk",
+    46304u32 => "deun",
+    46305u32 => "deun",
+    46306u32 => "deun",
+    46307u32 => "deut",
+    46308u32 => "deul",
+    46309u32 => "deuk",
+    46310u32 => "deum",
+    46311u32 => "deul",
+    46312u32 => "deul",
+    46313u32 => "deul",
+    46314u32 => "deup",
+    46315u32 => "deul",
+    46316u32 => "deum",
+    46317u32 => "deup",
+    46318u32 => "deup",
+    46319u32 => "deut",
+    46320u32 => "deut",
+    46321u32 => "deung",
+    46322u32 => "deut",
+    46323u32 => "deut",
+    46324u32 => "deuk",
+    46325u32 => "deut",
+    46326u32 => "deup",
+    46327u32 => "deut",
+    46328u32 => "dui",
+    46329u32 => "duik",
+    46330u32 => "duik",
+    46331u32 => "duik",
+    46332u32 => "duin",
+    46333u32 => "duin",
+    46334u32 => "duin",
+    46335u32 => "duit",
+};
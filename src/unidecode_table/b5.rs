@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B5: phf::Map<u32, &str> = phf_map!{
+    46336u32 => "duil",
+    46337u32 => "duik",
+    46338u32 => "duim",
+    46339u32 => "duil",
+    46340u32 => "duil",
+    46341u32 => "duil",
+    46342u32 => "duip",
+    46343u32 => "duil",
+    46344u32 => "duim",
+    46345u32 => "duip",
+    46346u32 => "duip",
+    46347u32 => "duit",
+    46348u32 => "duit",
+    46349u32 => "duing",
+    46350u32 => "duit",
+    46351u32 => "duit",
+    46352u32 => "duik",
+    46353u32 => "duit",
+    46354u32 => "duip",
+    46355u32 => "duit",
+    46356u32 => "di",
+    46357u32 => "dik",
+    46358u32 => "dik",
+    46359u32 => "dik",
+    46360u32 => "din",
+    46361u32 => "din",
+    46362u32 => "din",
+    46363u32 => "dit",
+    46364u32 => "dil",
+    46365u32 => "dik",
+    46366u32 => "dim",
+    46367u32 => "dil",
+    46368u32 => "dil",
+    46369u32 => "dil",
+    46370u32 => "dip",
+    46371u32 => "dil",
+    46372u32 => "dim",
+    46373u32 => "dip",
+    46374u32 => "dip",
+    46375u32 => "dit",
+    46376u32 => "dit",
+    46377u32 => "ding",
+    46378u32 => "dit",
+    46379u32 => "dit",
+    46380u32 => "dik",
+    46381u32 => "dit",
+    46382u32 => "dip",
+    46383u32 => "dit",
+    46384u32 => "tta",
+    46385u32 => "ttak",
+    46386u32 => "ttak",
+    46387u32 => "ttak",
+    46388u32 => "ttan",
+    46389u32 => "ttan",
+    46390u32 => "ttan",
+    46391u32 => "ttat",
+    46392u32 => "ttal",
+    46393u32 => "ttak",
+    46394u32 => "ttam",
+    46395u32 => "ttal",
+    46396u32 => "ttal",
+    46397u32 => "ttal",
+    46398u32 => "ttap",
+    46399u32 => "ttal",
+    46400u32 => "ttam",
+    46401u32 => "ttap",
+    46402u32 => "ttap",
+    46403u32 => "ttat",
+    46404u32 => "ttat",
+    46405u32 => "ttang",
+    46406u32 => "ttat",
+    46407u32 => "ttat",
+    46408u32 => "ttak",
+    46409u32 => "ttat",
+    46410u32 => "ttap",
+    46411u32 => "ttat",
+    46412u32 => "ttae",
+    46413u32 => "ttaek",
+    46414u32 => "ttaek",
+    46415u32 => "ttaek",
+    46416u32 => "ttaen",
+    46417u32 => "ttaen",
+    46418u32 => "ttaen",
+    46419u32 => "ttaet",
+    46420u32 => "ttael",
+    46421u32 => "ttaek",
+    46422u32 => "ttaem",
+    46423u32 => "ttael",
+    46424u32 => "ttael",
+    46425u32 => "ttael",
+    46426u32 => "ttaep",
+    46427u32 => "ttael",
+    46428u32 => "ttaem",
+    46429u32 => "ttaep",
+    46430u32 => "ttaep",
+    46431u32 => "ttaet",
+    46432u32 => "ttaet",
+    46433u32 => "ttaeng",
+    46434u32 => "ttaet",
+    46435u32 => "ttaet",
+    46436u32 => "ttaek",
+    46437u32 => "ttaet",
+    46438u32 => "ttaep",
+    46439u32 => "ttaet",
+    46440u32 => "ttya",
+    46441u32 => "ttyak",
+    46442u32 => "ttyak",
+    46443u32 => "ttyak",
+    46444u32 => "ttyan",
+    46445u32 => "ttyan",
+    46446u32 => "ttyan",
+    46447u32 => "ttyat",
+    46448u32 => "ttyal",
+    46449u32 => "ttyak",
+    46450u32 => "ttyam",
+    46451u32 => "ttyal",
+    46452u32 => "ttyal",
+    46453u32 => "ttyal",
+    46454u32 => "ttyap",
+    46455u32 => "ttyal",
+    46456u32 => "ttyam",
+    46457u32 => "ttyap",
+    46458u32 => "ttyap",
+    46459u32 => "ttyat",
+    46460u32 => "ttyat",
+    46461u32 => "ttyang",
+    46462u32 => "ttyat",
+    46463u32 => "ttyat",
+    46464u32 => "ttyak",
+    46465u32 => "ttyat",
+    46466u32 => "ttyap",
+    46467u32 => "ttyat",
+    46468u32 => "ttyae",
+    46469u32 => "ttyaek",
+    46470u32 => "ttyaek",
+    46471u32 => "ttyaek",
+    46472u32 => "ttyaen",
+    46473u32 => "ttyaen",
+    46474u32 => "ttyaen",
+    46475u32 => "ttyaet",
+    46476u32 => "ttyael",
+    46477u32 => "ttyaek",
+    46478u32 => "ttyaem",
+    46479u32 => "ttyael",
+    46480u32 => "ttyael",
+    46481u32 => "ttyael",
+    46482u32 => "ttyaep",
+    46483u32 => "ttyael",
+    46484u32 => "ttyaem",
+    46485u32 => "ttyaep",
+    46486u32 => "ttyaep",
+    46487u32 => "ttyaet",
+    46488u32 => "ttyaet",
+    46489u32 => "ttyaeng",
+    46490u32 => "ttyaet",
+    46491u32 => "ttyaet",
+    46492u32 => "ttyaek",
+    46493u32 => "ttyaet",
+    46494u32 => "ttyaep",
+    46495u32 => "ttyaet",
+    46496u32 => "tteo",
+    46497u32 => "tteok",
+    46498u32 => "tteok",
+    46499u32 => "tteok",
+    46500u32 => "tteon",
+    46501u32 => "tteon",
+    46502u32 => "tteon",
+    46503u32 => "tteot",
+    46504u32 => "tteol",
+    46505u32 => "tteok",
+    46506u32 => "tteom",
+    46507u32 => "tteol",
+    46508u32 => "tteol",
+    46509u32 => "tteol",
+    46510u32 => "tteop",
+    46511u32 => "tteol",
+    46512u32 => "tteom",
+    46513u32 => "tteop",
+    46514u32 => "tteop",
+    46515u32 => "tteot",
+    46516u32 => "tteot",
+    46517u32 => "tteong",
+    46518u32 => "tteot",
+    46519u32 => "tteot",
+    46520u32 => "tteok",
+    46521u32 => "tteot",
+    46522u32 => "tteop",
+    46523u32 => "tteot",
+    46524u32 => "tte",
+    46525u32 => "ttek",
+    46526u32 => "ttek",
+    46527u32 => "ttek",
+    46528u32 => "tten",
+    46529u32 => "tten",
+    46530u32 => "tten",
+    46531u32 => "ttet",
+    46532u32 => "ttel",
+    46533u32 => "ttek",
+    46534u32 => "ttem",
+    46535u32 => "ttel",
+    46536u32 => "ttel",
+    46537u32 => "ttel",
+    46538u32 => "ttep",
+    46539u32 => "ttel",
+    46540u32 => "ttem",
+    46541u32 => "ttep",
+    46542u32 => "ttep",
+    46543u32 => "ttet",
+    46544u32 => "ttet",
+    46545u32 => "tteng",
+    46546u32 => "ttet",
+    46547u32 => "ttet",
+    46548u32 => "ttek",
+    46549u32 => "ttet",
+    46550u32 => "ttep",
+    46551u32 => "ttet",
+    46552u32 => "ttyeo",
+    46553u32 => "ttyeok",
+    46554u32 => "ttyeok",
+    46555u32 => "ttyeok",
+    46556u32 => "ttyeon",
+    46557u32 => "ttyeon",
+    46558u32 => "ttyeon",
+    46559u32 => "ttyeot",
+    46560u32 => "ttyeol",
+    46561u32 => "ttyeok",
+    46562u32 => "ttyeom",
+    46563u32 => "ttyeol",
+    46564u32 => "ttyeol",
+    46565u32 => "ttyeol",
+    46566u32 => "ttyeop",
+    46567u32 => "ttyeol",
+    46568u32 => "ttyeom",
+    46569u32 => "ttyeop",
+    46570u32 => "ttyeop",
+    46571u32 => "ttyeot",
+    46572u32 => "ttyeot",
+    46573u32 => "ttyeong",
+    46574u32 => "ttyeot",
+    46575u32 => "ttyeot",
+    46576u32 => "ttyeok",
+    46577u32 => "ttyeot",
+    46578u32 => "ttyeop",
+    46579u32 => "ttyeot",
+    46580u32 => "ttye",
+    46581u32 => "ttyek",
+    46582u32 => "ttyek",
+    46583u32 => "ttyek",
+    46584u32 => "ttyen",
+    46585u32 => "ttyen",
+    46586u32 => "ttyen",
+    46587u32 => "ttyet",
+    46588u32 => "ttyel",
+    46589u32 => "ttyek",
+    46590u32 => "ttyem",
+    46591u32 => "ttyel",
+};
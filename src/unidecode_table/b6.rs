@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B6: phf::Map<u32, &str> = phf_map!{
+    46592u32 => "ttyel",
+    46593u32 => "ttyel",
+    46594u32 => "ttyep",
+    46595u32 => "ttyel",
+    46596u32 => "ttyem",
+    46597u32 => "ttyep",
+    46598u32 => "ttyep",
+    46599u32 => "ttyet",
+    46600u32 => "ttyet",
+    46601u32 => "ttyeng",
+    46602u32 => "ttyet",
+    46603u32 => "ttyet",
+    46604u32 => "ttyek",
+    46605u32 => "ttyet",
+    46606u32 => "ttyep",
+    46607u32 => "ttyet",
+    46608u32 => "tto",
+    46609u32 => "ttok",
+    46610u32 => "ttok",
+    46611u32 => "ttok",
+    46612u32 => "tton",
+    46613u32 => "tton",
+    46614u32 => "tton",
+    46615u32 => "ttot",
+    46616u32 => "ttol",
+    46617u32 => "ttok",
+    46618u32 => "ttom",
+    46619u32 => "ttol",
+    46620u32 => "ttol",
+    46621u32 => "ttol",
+    46622u32 => "ttop",
+    46623u32 => "ttol",
+    46624u32 => "ttom",
+    46625u32 => "ttop",
+    46626u32 => "ttop",
+    46627u32 => "ttot",
+    46628u32 => "ttot",
+    46629u32 => "ttong",
+    46630u32 => "ttot",
+    46631u32 => "ttot",
+    46632u32 => "ttok",
+    46633u32 => "ttot",
+    46634u32 => "ttop",
+    46635u32 => "ttot",
+    46636u32 => "ttwa",
+    46637u32 => "ttwak",
+    46638u32 => "ttwak",
+    46639u32 => "ttwak",
+    46640u32 => "ttwan",
+    46641u32 => "ttwan",
+    46642u32 => "ttwan",
+    46643u32 => "ttwat",
+    46644u32 => "ttwal",
+    46645u32 => "ttwak",
+    46646u32 => "ttwam",
+    46647u32 => "ttwal",
+    46648u32 => "ttwal",
+    46649u32 => "ttwal",
+    46650u32 => "ttwap",
+    46651u32 => "ttwal",
+    46652u32 => "ttwam",
+    46653u32 => "ttwap",
+    46654u32 => "ttwap",
+    46655u32 => "ttwat",
+    46656u32 => "ttwat",
+    46657u32 => "ttwang",
+    46658u32 => "ttwat",
+    46659u32 => "ttwat",
+    46660u32 => "ttwak",
+    46661u32 => "ttwat",
+    46662u32 => "ttwap",
+    46663u32 => "ttwat",
+    46664u32 => "ttwae",
+    46665u32 => "ttwaek",
+    46666u32 => "ttwaek",
+    46667u32 => "ttwaek",
+    46668u32 => "ttwaen",
+    46669u32 => "ttwaen",
+    46670u32 => "ttwaen",
+    46671u32 => "ttwaet",
+    46672u32 => "ttwael",
+    46673u32 => "ttwaek",
+    46674u32 => "ttwaem",
+    46675u32 => "ttwael",
+    46676u32 => "ttwael",
+    46677u32 => "ttwael",
+    46678u32 => "ttwaep",
+    46679u32 => "ttwael",
+    46680u32 => "ttwaem",
+    46681u32 => "ttwaep",
+    46682u32 => "ttwaep",
+    46683u32 => "ttwaet",
+    46684u32 => "ttwaet",
+    46685u32 => "ttwaeng",
+    46686u32 => "ttwaet",
+    46687u32 => "ttwaet",
+    46688u32 => "ttwaek",
+    46689u32 => "ttwaet",
+    46690u32 => "ttwaep",
+    46691u32 => "ttwaet",
+    46692u32 => "ttoe",
+    46693u32 => "ttoek",
+    46694u32 => "ttoek",
+    46695u32 => "ttoek",
+    46696u32 => "ttoen",
+    46697u32 => "ttoen",
+    46698u32 => "ttoen",
+    46699u32 => "ttoet",
+    46700u32 => "ttoel",
+    46701u32 => "ttoek",
+    46702u32 => "ttoem",
+    46703u32 => "ttoel",
+    46704u32 => "ttoel",
+    46705u32 => "ttoel",
+    46706u32 => "ttoep",
+    46707u32 => "ttoel",
+    46708u32 => "ttoem",
+    46709u32 => "ttoep",
+    46710u32 => "ttoep",
+    46711u32 => "ttoet",
+    46712u32 => "ttoet",
+    46713u32 => "ttoeng",
+    46714u32 => "ttoet",
+    46715u32 => "ttoet",
+    46716u32 => "ttoek",
+    46717u32 => "ttoet",
+    46718u32 => "ttoep",
+    46719u32 => "ttoet",
+    46720u32 => "ttyo",
+    46721u32 => "ttyok",
+    46722u32 => "ttyok",
+    46723u32 => "ttyok",
+    46724u32 => "ttyon",
+    46725u32 => "ttyon",
+    46726u32 => "ttyon",
+    46727u32 => "ttyot",
+    46728u32 => "ttyol",
+    46729u32 => "ttyok",
+    46730u32 => "ttyom",
+    46731u32 => "ttyol",
+    46732u32 => "ttyol",
+    46733u32 => "ttyol",
+    46734u32 => "ttyop",
+    46735u32 => "ttyol",
+    46736u32 => "ttyom",
+    46737u32 => "ttyop",
+    46738u32 => "ttyop",
+    46739u32 => "ttyot",
+    46740u32 => "ttyot",
+    46741u32 => "ttyong",
+    46742u32 => "ttyot",
+    46743u32 => "ttyot",
+    46744u32 => "ttyok",
+    46745u32 => "ttyot",
+    46746u32 => "ttyop",
+    46747u32 => "ttyot",
+    46748u32 => "ttu",
+    46749u32 => "ttuk",
+    46750u32 => "ttuk",
+    46751u32 => "ttuk",
+    46752u32 => "ttun",
+    46753u32 => "ttun",
+    46754u32 => "ttun",
+    46755u32 => "ttut",
+    46756u32 => "ttul",
+    46757u32 => "ttuk",
+    46758u32 => "ttum",
+    46759u32 => "ttul",
+    46760u32 => "ttul",
+    46761u32 => "ttul",
+    46762u32 => "ttup",
+    46763u32 => "ttul",
+    46764u32 => "ttum",
+    46765u32 => "ttup",
+    46766u32 => "ttup",
+    46767u32 => "ttut",
+    46768u32 => "ttut",
+    46769u32 => "ttung",
+    46770u32 => "ttut",
+    46771u32 => "ttut",
+    46772u32 => "ttuk",
+    46773u32 => "ttut",
+    46774u32 => "ttup",
+    46775u32 => "ttut",
+    46776u32 => "ttwo",
+    46777u32 => "ttwok",
+    46778u32 => "ttwok",
+    46779u32 => "ttwok",
+    46780u32 => "ttwon",
+    46781u32 => "ttwon",
+    46782u32 => "ttwon",
+    46783u32 => "ttwot",
+    46784u32 => "ttwol",
+    46785u32 => "ttwok",
+    46786u32 => "ttwom",
+    46787u32 => "ttwol",
+    46788u32 => "ttwol",
+    46789u32 => "ttwol",
+    46790u32 => "ttwop",
+    46791u32 => "ttwol",
+    46792u32 => "ttwom",
+    46793u32 => "ttwop",
+    46794u32 => "ttwop",
+    46795u32 => "ttwot",
+    46796u32 => "ttwot",
+    46797u32 => "ttwong",
+    46798u32 => "ttwot",
+    46799u32 => "ttwot",
+    46800u32 => "ttwok",
+    46801u32 => "ttwot",
+    46802u32 => "ttwop",
+    46803u32 => "ttwot",
+    46804u32 => "ttwe",
+    46805u32 => "ttwek",
+    46806u32 => "ttwek",
+    46807u32 => "ttwek",
+    46808u32 => "ttwen",
+    46809u32 => "ttwen",
+    46810u32 => "ttwen",
+    46811u32 => "ttwet",
+    46812u32 => "ttwel",
+    46813u32 => "ttwek",
+    46814u32 => "ttwem",
+    46815u32 => "ttwel",
+    46816u32 => "ttwel",
+    46817u32 => "ttwel",
+    46818u32 => "ttwep",
+    46819u32 => "ttwel",
+    46820u32 => "ttwem",
+    46821u32 => "ttwep",
+    46822u32 => "ttwep",
+    46823u32 => "ttwet",
+    46824u32 => "ttwet",
+    46825u32 => "ttweng",
+    46826u32 => "ttwet",
+    46827u32 => "ttwet",
+    46828u32 => "ttwek",
+    46829u32 => "ttwet",
+    46830u32 => "ttwep",
+    46831u32 => "ttwet",
+    46832u32 => "ttwi",
+    46833u32 => "ttwik",
+    46834u32 => "ttwik",
+    46835u32 => "ttwik",
+    46836u32 => "ttwin",
+    46837u32 => "ttwin",
+    46838u32 => "ttwin",
+    46839u32 => "ttwit",
+    46840u32 => "ttwil",
+    46841u32 => "ttwik",
+    46842u32 => "ttwim",
+    46843u32 => "ttwil",
+    46844u32 => "ttwil",
+    46845u32 => "ttwil",
+    46846u32 => "ttwip",
+    46847u32 => "ttwil",
+};
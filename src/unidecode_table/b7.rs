@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B7: phf::Map<u32, &str> = phf_map!{
+    46848u32 => "ttwim",
+    46849u32 => "ttwip",
+    46850u32 => "ttwip",
+    46851u32 => "ttwit",
+    46852u32 => "ttwit",
+    46853u32 => "ttwing",
+    46854u32 => "ttwit",
+    46855u32 => "ttwit",
+    46856u32 => "ttwik",
+    46857u32 => "ttwit",
+    46858u32 => "ttwip",
+    46859u32 => "ttwit",
+    46860u32 => "ttyu",
+    46861u32 => "ttyuk",
+    46862u32 => "ttyuk",
+    46863u32 => "ttyuk",
+    46864u32 => "ttyun",
+    46865u32 => "ttyun",
+    46866u32 => "ttyun",
+    46867u32 => "ttyut",
+    46868u32 => "ttyul",
+    46869u32 => "ttyuk",
+    46870u32 => "ttyum",
+    46871u32 => "ttyul",
+    46872u32 => "ttyul",
+    46873u32 => "ttyul",
+    46874u32 => "ttyup",
+    46875u32 => "ttyul",
+    46876u32 => "ttyum",
+    46877u32 => "ttyup",
+    46878u32 => "ttyup",
+    46879u32 => "ttyut",
+    46880u32 => "ttyut",
+    46881u32 => "ttyung",
+    46882u32 => "ttyut",
+    46883u32 => "ttyut",
+    46884u32 => "ttyuk",
+    46885u32 => "ttyut",
+    46886u32 => "ttyup",
+    46887u32 => "ttyut",
+    46888u32 => "tteu",
+    46889u32 => "tteuk",
+    46890u32 => "tteuk",
+    46891u32 => "tteuk",
+    46892u32 => "tteun",
+    46893u32 => "tteun",
+    46894u32 => "tteun",
+    46895u32 => "tteut",
+    46896u32 => "tteul",
+    46897u32 => "tteuk",
+    46898u32 => "tteum",
+    46899u32 => "tteul",
+    46900u32 => "tteul",
+    46901u32 => "tteul",
+    46902u32 => "tteup",
+    46903u32 => "tteul",
+    46904u32 => "tteum",
+    46905u32 => "tteup",
+    46906u32 => "tteup",
+    46907u32 => "tteut",
+    46908u32 => "tteut",
+    46909u32 => "tteung",
+    46910u32 => "tteut",
+    46911u32 => "tteut",
+    46912u32 => "tteuk",
+    46913u32 => "tteut",
+    46914u32 => "tteup",
+    46915u32 => "tteut",
+    46916u32 => "ttui",
+    46917u32 => "ttuik",
+    46918u32 => "ttuik",
+    46919u32 => "ttuik",
+    46920u32 => "ttuin",
+    46921u32 => "ttuin",
+    46922u32 => "ttuin",
+    46923u32 => "ttuit",
+    46924u32 => "ttuil",
+    46925u32 => "ttuik",
+    46926u32 => "ttuim",
+    46927u32 => "ttuil",
+    46928u32 => "ttuil",
+    46929u32 => "ttuil",
+    46930u32 => "ttuip",
+    46931u32 => "ttuil",
+    46932u32 => "ttuim",
+    46933u32 => "ttuip",
+    46934u32 => "ttuip",
+    46935u32 => "ttuit",
+    46936u32 => "ttuit",
+    46937u32 => "ttuing",
+    46938u32 => "ttuit",
+    46939u32 => "ttuit",
+    46940u32 => "ttuik",
+    46941u32 => "ttuit",
+    46942u32 => "ttuip",
+    46943u32 => "ttuit",
+    46944u32 => "tti",
+    46945u32 => "ttik",
+    46946u32 => "ttik",
+    46947u32 => "ttik",
+    46948u32 => "ttin",
+    46949u32 => "ttin",
+    46950u32 => "ttin",
+    46951u32 => "ttit",
+    46952u32 => "ttil",
+    46953u32 => "ttik",
+    46954u32 => "ttim",
+    46955u32 => "ttil",
+    46956u32 => "ttil",
+    46957u32 => "ttil",
+    46958u32 => "ttip",
+    46959u32 => "ttil",
+    46960u32 => "ttim",
+    46961u32 => "ttip",
+    46962u32 => "ttip",
+    46963u32 => "ttit",
+    46964u32 => "ttit",
+    46965u32 => "tting",
+    46966u32 => "ttit",
+    46967u32 => "ttit",
+    46968u32 => "ttik",
+    46969u32 => "ttit",
+    46970u32 => "ttip",
+    46971u32 => "ttit",
+    46972u32 => "ra",
+    46973u32 => "rak",
+    46974u32 => "rak",
+    46975u32 => "rak",
+    46976u32 => "ran",
+    46977u32 => "ran",
+    46978u32 => "ran",
+    46979u32 => "rat",
+    46980u32 => "ral",
+    46981u32 => "rak",
+    46982u32 => "ram",
+    46983u32 => "ral",
+    46984u32 => "ral",
+    46985u32 => "ral",
+    46986u32 => "rap",
+    46987u32 => "ral",
+    46988u32 => "ram",
+    46989u32 => "rap",
+    46990u32 => "rap",
+    46991u32 => "rat",
+    46992u32 => "rat",
+    46993u32 => "rang",
+    46994u32 => "rat",
+    46995u32 => "rat",
+    46996u32 => "rak",
+    46997u32 => "rat",
+    46998u32 => "rap",
+    46999u32 => "rat",
+    47000u32 => "rae",
+    47001u32 => "raek",
+    47002u32 => "raek",
+    47003u32 => "raek",
+    47004u32 => "raen",
+    47005u32 => "raen",
+    47006u32 => "raen",
+    47007u32 => "raet",
+    47008u32 => "rael",
+    47009u32 => "raek",
+    47010u32 => "raem",
+    47011u32 => "rael",
+    47012u32 => "rael",
+    47013u32 => "rael",
+    47014u32 => "raep",
+    47015u32 => "rael",
+    47016u32 => "raem",
+    47017u32 => "raep",
+    47018u32 => "raep",
+    47019u32 => "raet",
+    47020u32 => "raet",
+    47021u32 => "raeng",
+    47022u32 => "raet",
+    47023u32 => "raet",
+    47024u32 => "raek",
+    47025u32 => "raet",
+    47026u32 => "raep",
+    47027u32 => "raet",
+    47028u32 => "rya",
+    47029u32 => "ryak",
+    47030u32 => "ryak",
+    47031u32 => "ryak",
+    47032u32 => "ryan",
+    47033u32 => "ryan",
+    47034u32 => "ryan",
+    47035u32 => "ryat",
+    47036u32 => "ryal",
+    47037u32 => "ryak",
+    47038u32 => "ryam",
+    47039u32 => "ryal",
+    47040u32 => "ryal",
+    47041u32 => "ryal",
+    47042u32 => "ryap",
+    47043u32 => "ryal",
+    47044u32 => "ryam",
+    47045u32 => "ryap",
+    47046u32 => "ryap",
+    47047u32 => "ryat",
+    47048u32 => "ryat",
+    47049u32 => "ryang",
+    47050u32 => "ryat",
+    47051u32 => "ryat",
+    47052u32 => "ryak",
+    47053u32 => "ryat",
+    47054u32 => "ryap",
+    47055u32 => "ryat",
+    47056u32 => "ryae",
+    47057u32 => "ryaek",
+    47058u32 => "ryaek",
+    47059u32 => "ryaek",
+    47060u32 => "ryaen",
+    47061u32 => "ryaen",
+    47062u32 => "ryaen",
+    47063u32 => "ryaet",
+    47064u32 => "ryael",
+    47065u32 => "ryaek",
+    47066u32 => "ryaem",
+    47067u32 => "ryael",
+    47068u32 => "ryael",
+    47069u32 => "ryael",
+    47070u32 => "ryaep",
+    47071u32 => "ryael",
+    47072u32 => "ryaem",
+    47073u32 => "ryaep",
+    47074u32 => "ryaep",
+    47075u32 => "ryaet",
+    47076u32 => "ryaet",
+    47077u32 => "ryaeng",
+    47078u32 => "ryaet",
+    47079u32 => "ryaet",
+    47080u32 => "ryaek",
+    47081u32 => "ryaet",
+    47082u32 => "ryaep",
+    47083u32 => "ryaet",
+    47084u32 => "reo",
+    47085u32 => "reok",
+    47086u32 => "reok",
+    47087u32 => "reok",
+    47088u32 => "reon",
+    47089u32 => "reon",
+    47090u32 => "reon",
+    47091u32 => "reot",
+    47092u32 => "reol",
+    47093u32 => "reok",
+    47094u32 => "reom",
+    47095u32 => "reol",
+    47096u32 => "reol",
+    47097u32 => "reol",
+    47098u32 => "reop",
+    47099u32 => "reol",
+    47100u32 => "reom",
+    47101u32 => "reop",
+    47102u32 => "reop",
+    47103u32 => "reot",
+};
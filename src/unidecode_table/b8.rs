@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B8: phf::Map<u32, &str> = phf_map!{
+    47104u32 => "reot",
+    47105u32 => "reong",
+    47106u32 => "reot",
+    47107u32 => "reot",
+    47108u32 => "reok",
+    47109u32 => "reot",
+    47110u32 => "reop",
+    47111u32 => "reot",
+    47112u32 => "re",
+    47113u32 => "rek",
+    47114u32 => "rek",
+    47115u32 => "rek",
+    47116u32 => "ren",
+    47117u32 => "ren",
+    47118u32 => "ren",
+    47119u32 => "ret",
+    47120u32 => "rel",
+    47121u32 => "rek",
+    47122u32 => "rem",
+    47123u32 => "rel",
+    47124u32 => "rel",
+    47125u32 => "rel",
+    47126u32 => "rep",
+    47127u32 => "rel",
+    47128u32 => "rem",
+    47129u32 => "rep",
+    47130u32 => "rep",
+    47131u32 => "ret",
+    47132u32 => "ret",
+    47133u32 => "reng",
+    47134u32 => "ret",
+    47135u32 => "ret",
+    47136u32 => "rek",
+    47137u32 => "ret",
+    47138u32 => "rep",
+    47139u32 => "ret",
+    47140u32 => "ryeo",
+    47141u32 => "ryeok",
+    47142u32 => "ryeok",
+    47143u32 => "ryeok",
+    47144u32 => "ryeon",
+    47145u32 => "ryeon",
+    47146u32 => "ryeon",
+    47147u32 => "ryeot",
+    47148u32 => "ryeol",
+    47149u32 => "ryeok",
+    47150u32 => "ryeom",
+    47151u32 => "ryeol",
+    47152u32 => "ryeol",
+    47153u32 => "ryeol",
+    47154u32 => "ryeop",
+    47155u32 => "ryeol",
+    47156u32 => "ryeom",
+    47157u32 => "ryeop",
+    47158u32 => "ryeop",
+    47159u32 => "ryeot",
+    47160u32 => "ryeot",
+    47161u32 => "ryeong",
+    47162u32 => "ryeot",
+    47163u32 => "ryeot",
+    47164u32 => "ryeok",
+    47165u32 => "ryeot",
+    47166u32 => "ryeop",
+    47167u32 => "ryeot",
+    47168u32 => "rye",
+    47169u32 => "ryek",
+    47170u32 => "ryek",
+    47171u32 => "ryek",
+    47172u32 => "ryen",
+    47173u32 => "ryen",
+    47174u32 => "ryen",
+    47175u32 => "ryet",
+    47176u32 => "ryel",
+    47177u32 => "ryek",
+    47178u32 => "ryem",
+    47179u32 => "ryel",
+    47180u32 => "ryel",
+    47181u32 => "ryel",
+    47182u32 => "ryep",
+    47183u32 => "ryel",
+    47184u32 => "ryem",
+    47185u32 => "ryep",
+    47186u32 => "ryep",
+    47187u32 => "ryet",
+    47188u32 => "ryet",
+    47189u32 => "ryeng",
+    47190u32 => "ryet",
+    47191u32 => "ryet",
+    47192u32 => "ryek",
+    47193u32 => "ryet",
+    47194u32 => "ryep",
+    47195u32 => "ryet",
+    47196u32 => "ro",
+    47197u32 => "rok",
+    47198u32 => "rok",
+    47199u32 => "rok",
+    47200u32 => "ron",
+    47201u32 => "ron",
+    47202u32 => "ron",
+    47203u32 => "rot",
+    47204u32 => "rol",
+    47205u32 => "rok",
+    47206u32 => "rom",
+    47207u32 => "rol",
+    47208u32 => "rol",
+    47209u32 => "rol",
+    47210u32 => "rop",
+    47211u32 => "rol",
+    47212u32 => "rom",
+    47213u32 => "rop",
+    47214u32 => "rop",
+    47215u32 => "rot",
+    47216u32 => "rot",
+    47217u32 => "rong",
+    47218u32 => "rot",
+    47219u32 => "rot",
+    47220u32 => "rok",
+    47221u32 => "rot",
+    47222u32 => "rop",
+    47223u32 => "rot",
+    47224u32 => "rwa",
+    47225u32 => "rwak",
+    47226u32 => "rwak",
+    47227u32 => "rwak",
+    47228u32 => "rwan",
+    47229u32 => "rwan",
+    47230u32 => "rwan",
+    47231u32 => "rwat",
+    47232u32 => "rwal",
+    47233u32 => "rwak",
+    47234u32 => "rwam",
+    47235u32 => "rwal",
+    47236u32 => "rwal",
+    47237u32 => "rwal",
+    47238u32 => "rwap",
+    47239u32 => "rwal",
+    47240u32 => "rwam",
+    47241u32 => "rwap",
+    47242u32 => "rwap",
+    47243u32 => "rwat",
+    47244u32 => "rwat",
+    47245u32 => "rwang",
+    47246u32 => "rwat",
+    47247u32 => "rwat",
+    47248u32 => "rwak",
+    47249u32 => "rwat",
+    47250u32 => "rwap",
+    47251u32 => "rwat",
+    47252u32 => "rwae",
+    47253u32 => "rwaek",
+    47254u32 => "rwaek",
+    47255u32 => "rwaek",
+    47256u32 => "rwaen",
+    47257u32 => "rwaen",
+    47258u32 => "rwaen",
+    47259u32 => "rwaet",
+    47260u32 => "rwael",
+    47261u32 => "rwaek",
+    47262u32 => "rwaem",
+    47263u32 => "rwael",
+    47264u32 => "rwael",
+    47265u32 => "rwael",
+    47266u32 => "rwaep",
+    47267u32 => "rwael",
+    47268u32 => "rwaem",
+    47269u32 => "rwaep",
+    47270u32 => "rwaep",
+    47271u32 => "rwaet",
+    47272u32 => "rwaet",
+    47273u32 => "rwaeng",
+    47274u32 => "rwaet",
+    47275u32 => "rwaet",
+    47276u32 => "rwaek",
+    47277u32 => "rwaet",
+    47278u32 => "rwaep",
+    47279u32 => "rwaet",
+    47280u32 => "roe",
+    47281u32 => "roek",
+    47282u32 => "roek",
+    47283u32 => "roek",
+    47284u32 => "roen",
+    47285u32 => "roen",
+    47286u32 => "roen",
+    47287u32 => "roet",
+    47288u32 => "roel",
+    47289u32 => "roek",
+    47290u32 => "roem",
+    47291u32 => "roel",
+    47292u32 => "roel",
+    47293u32 => "roel",
+    47294u32 => "roep",
+    47295u32 => "roel",
+    47296u32 => "roem",
+    47297u32 => "roep",
+    47298u32 => "roep",
+    47299u32 => "roet",
+    47300u32 => "roet",
+    47301u32 => "roeng",
+    47302u32 => "roet",
+    47303u32 => "roet",
+    47304u32 => "roek",
+    47305u32 => "roet",
+    47306u32 => "roep",
+    47307u32 => "roet",
+    47308u32 => "ryo",
+    47309u32 => "ryok",
+    47310u32 => "ryok",
+    47311u32 => "ryok",
+    47312u32 => "ryon",
+    47313u32 => "ryon",
+    47314u32 => "ryon",
+    47315u32 => "ryot",
+    47316u32 => "ryol",
+    47317u32 => "ryok",
+    47318u32 => "ryom",
+    47319u32 => "ryol",
+    47320u32 => "ryol",
+    47321u32 => "ryol",
+    47322u32 => "ryop",
+    47323u32 => "ryol",
+    47324u32 => "ryom",
+    47325u32 => "ryop",
+    47326u32 => "ryop",
+    47327u32 => "ryot",
+    47328u32 => "ryot",
+    47329u32 => "ryong",
+    47330u32 => "ryot",
+    47331u32 => "ryot",
+    47332u32 => "ryok",
+    47333u32 => "ryot",
+    47334u32 => "ryop",
+    47335u32 => "ryot",
+    47336u32 => "ru",
+    47337u32 => "ruk",
+    47338u32 => "ruk",
+    47339u32 => "ruk",
+    47340u32 => "run",
+    47341u32 => "run",
+    47342u32 => "run",
+    47343u32 => "rut",
+    47344u32 => "rul",
+    47345u32 => "ruk",
+    47346u32 => "rum",
+    47347u32 => "rul",
+    47348u32 => "rul",
+    47349u32 => "rul",
+    47350u32 => "rup",
+    47351u32 => "rul",
+    47352u32 => "rum",
+    47353u32 => "rup",
+    47354u32 => "rup",
+    47355u32 => "rut",
+    47356u32 => "rut",
+    47357u32 => "rung",
+    47358u32 => "rut",
+    47359u32 => "rut",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_B9: phf::Map<u32, &str> = phf_map!{
+    47360u32 => "ruk",
+    47361u32 => "rut",
+    47362u32 => "rup",
+    47363u32 => "rut",
+    47364u32 => "rwo",
+    47365u32 => "rwok",
+    47366u32 => "rwok",
+    47367u32 => "rwok",
+    47368u32 => "rwon",
+    47369u32 => "rwon",
+    47370u32 => "rwon",
+    47371u32 => "rwot",
+    47372u32 => "rwol",
+    47373u32 => "rwok",
+    47374u32 => "rwom",
+    47375u32 => "rwol",
+    47376u32 => "rwol",
+    47377u32 => "rwol",
+    47378u32 => "rwop",
+    47379u32 => "rwol",
+    47380u32 => "rwom",
+    47381u32 => "rwop",
+    47382u32 => "rwop",
+    47383u32 => "rwot",
+    47384u32 => "rwot",
+    47385u32 => "rwong",
+    47386u32 => "rwot",
+    47387u32 => "rwot",
+    47388u32 => "rwok",
+    47389u32 => "rwot",
+    47390u32 => "rwop",
+    47391u32 => "rwot",
+    47392u32 => "rwe",
+    47393u32 => "rwek",
+    47394u32 => "rwek",
+    47395u32 => "rwek",
+    47396u32 => "rwen",
+    47397u32 => "rwen",
+    47398u32 => "rwen",
+    47399u32 => "rwet",
+    47400u32 => "rwel",
+    47401u32 => "rwek",
+    47402u32 => "rwem",
+    47403u32 => "rwel",
+    47404u32 => "rwel",
+    47405u32 => "rwel",
+    47406u32 => "rwep",
+    47407u32 => "rwel",
+    47408u32 => "rwem",
+    47409u32 => "rwep",
+    47410u32 => "rwep",
+    47411u32 => "rwet",
+    47412u32 => "rwet",
+    47413u32 => "rweng",
+    47414u32 => "rwet",
+    47415u32 => "rwet",
+    47416u32 => "rwek",
+    47417u32 => "rwet",
+    47418u32 => "rwep",
+    47419u32 => "rwet",
+    47420u32 => "rwi",
+    47421u32 => "rwik",
+    47422u32 => "rwik",
+    47423u32 => "rwik",
+    47424u32 => "rwin",
+    47425u32 => "rwin",
+    47426u32 => "rwin",
+    47427u32 => "rwit",
+    47428u32 => "rwil",
+    47429u32 => "rwik",
+    47430u32 => "rwim",
+    47431u32 => "rwil",
+    47432u32 => "rwil",
+    47433u32 => "rwil",
+    47434u32 => "rwip",
+    47435u32 => "rwil",
+    47436u32 => "rwim",
+    47437u32 => "rwip",
+    47438u32 => "rwip",
+    47439u32 => "rwit",
+    47440u32 => "rwit",
+    47441u32 => "rwing",
+    47442u32 => "rwit",
+    47443u32 => "rwit",
+    47444u32 => "rwik",
+    47445u32 => "rwit",
+    47446u32 => "rwip",
+    47447u32 => "rwit",
+    47448u32 => "ryu",
+    47449u32 => "ryuk",
+    47450u32 => "ryuk",
+    47451u32 => "ryuk",
+    47452u32 => "ryun",
+    47453u32 => "ryun",
+    47454u32 => "ryun",
+    47455u32 => "ryut",
+    47456u32 => "ryul",
+    47457u32 => "ryuk",
+    47458u32 => "ryum",
+    47459u32 => "ryul",
+    47460u32 => "ryul",
+    47461u32 => "ryul",
+    47462u32 => "ryup",
+    47463u32 => "ryul",
+    47464u32 => "ryum",
+    47465u32 => "ryup",
+    47466u32 => "ryup",
+    47467u32 => "ryut",
+    47468u32 => "ryut",
+    47469u32 => "ryung",
+    47470u32 => "ryut",
+    47471u32 => "ryut",
+    47472u32 => "ryuk",
+    47473u32 => "ryut",
+    47474u32 => "ryup",
+    47475u32 => "ryut",
+    47476u32 => "reu",
+    47477u32 => "reuk",
+    47478u32 => "reuk",
+    47479u32 => "reuk",
+    47480u32 => "reun",
+    47481u32 => "reun",
+    47482u32 => "reun",
+    47483u32 => "reut",
+    47484u32 => "reul",
+    47485u32 => "reuk",
+    47486u32 => "reum",
+    47487u32 => "reul",
+    47488u32 => "reul",
+    47489u32 => "reul",
+    47490u32 => "reup",
+    47491u32 => "reul",
+    47492u32 => "reum",
+    47493u32 => "reup",
+    47494u32 => "reup",
+    47495u32 => "reut",
+    47496u32 => "reut",
+    47497u32 => "reung",
+    47498u32 => "reut",
+    47499u32 => "reut",
+    47500u32 => "reuk",
+    47501u32 => "reut",
+    47502u32 => "reup",
+    47503u32 => "reut",
+    47504u32 => "rui",
+    47505u32 => "ruik",
+    47506u32 => "ruik",
+    47507u32 => "ruik",
+    47508u32 => "ruin",
+    47509u32 => "ruin",
+    47510u32 => "ruin",
+    47511u32 => "ruit",
+    47512u32 => "ruil",
+    47513u32 => "ruik",
+    47514u32 => "ruim",
+    47515u32 => "ruil",
+    47516u32 => "ruil",
+    47517u32 => "ruil",
+    47518u32 => "ruip",
+    47519u32 => "ruil",
+    47520u32 => "ruim",
+    47521u32 => "ruip",
+    47522u32 => "ruip",
+    47523u32 => "ruit",
+    47524u32 => "ruit",
+    47525u32 => "ruing",
+    47526u32 => "ruit",
+    47527u32 => "ruit",
+    47528u32 => "ruik",
+    47529u32 => "ruit",
+    47530u32 => "ruip",
+    47531u32 => "ruit",
+    47532u32 => "ri",
+    47533u32 => "rik",
+    47534u32 => "rik",
+    47535u32 => "rik",
+    47536u32 => "rin",
+    47537u32 => "rin",
+    47538u32 => "rin",
+    47539u32 => "rit",
+    47540u32 => "ril",
+    47541u32 => "rik",
+    47542u32 => "rim",
+    47543u32 => "ril",
+    47544u32 => "ril",
+    47545u32 => "ril",
+    47546u32 => "rip",
+    47547u32 => "ril",
+    47548u32 => "rim",
+    47549u32 => "rip",
+    47550u32 => "rip",
+    47551u32 => "rit",
+    47552u32 => "rit",
+    47553u32 => "ring",
+    47554u32 => "rit",
+    47555u32 => "rit",
+    47556u32 => "rik",
+    47557u32 => "rit",
+    47558u32 => "rip",
+    47559u32 => "rit",
+    47560u32 => "ma",
+    47561u32 => "mak",
+    47562u32 => "mak",
+    47563u32 => "mak",
+    47564u32 => "man",
+    47565u32 => "man",
+    47566u32 => "man",
+    47567u32 => "mat",
+    47568u32 => "mal",
+    47569u32 => "mak",
+    47570u32 => "mam",
+    47571u32 => "mal",
+    47572u32 => "mal",
+    47573u32 => "mal",
+    47574u32 => "map",
+    47575u32 => "mal",
+    47576u32 => "mam",
+    47577u32 => "map",
+    47578u32 => "map",
+    47579u32 => "mat",
+    47580u32 => "mat",
+    47581u32 => "mang",
+    47582u32 => "mat",
+    47583u32 => "mat",
+    47584u32 => "mak",
+    47585u32 => "mat",
+    47586u32 => "map",
+    47587u32 => "mat",
+    47588u32 => "mae",
+    47589u32 => "maek",
+    47590u32 => "maek",
+    47591u32 => "maek",
+    47592u32 => "maen",
+    47593u32 => "maen",
+    47594u32 => "maen",
+    47595u32 => "maet",
+    47596u32 => "mael",
+    47597u32 => "maek",
+    47598u32 => "maem",
+    47599u32 => "mael",
+    47600u32 => "mael",
+    47601u32 => "mael",
+    47602u32 => "maep",
+    47603u32 => "mael",
+    47604u32 => "maem",
+    47605u32 => "maep",
+    47606u32 => "maep",
+    47607u32 => "maet",
+    47608u32 => "maet",
+    47609u32 => "maeng",
+    47610u32 => "maet",
+    47611u32 => "maet",
+    47612u32 => "maek",
+    47613u32 => "maet",
+    47614u32 => "maep",
+    47615u32 => "maet",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BA: phf::Map<u32, &str> = phf_map!{
+    47616u32 => "mya",
+    47617u32 => "myak",
+    47618u32 => "myak",
+    47619u32 => "myak",
+    47620u32 => "myan",
+    47621u32 => "myan",
+    47622u32 => "myan",
+    47623u32 => "myat",
+    47624u32 => "myal",
+    47625u32 => "myak",
+    47626u32 => "myam",
+    47627u32 => "myal",
+    47628u32 => "myal",
+    47629u32 => "myal",
+    47630u32 => "myap",
+    47631u32 => "myal",
+    47632u32 => "myam",
+    47633u32 => "myap",
+    47634u32 => "myap",
+    47635u32 => "myat",
+    47636u32 => "myat",
+    47637u32 => "myang",
+    47638u32 => "myat",
+    47639u32 => "myat",
+    47640u32 => "myak",
+    47641u32 => "myat",
+    47642u32 => "myap",
+    47643u32 => "myat",
+    47644u32 => "myae",
+    47645u32 => "myaek",
+    47646u32 => "myaek",
+    47647u32 => "myaek",
+    47648u32 => "myaen",
+    47649u32 => "myaen",
+    47650u32 => "myaen",
+    47651u32 => "myaet",
+    47652u32 => "myael",
+    47653u32 => "myaek",
+    47654u32 => "myaem",
+    47655u32 => "myael",
+    47656u32 => "myael",
+    47657u32 => "myael",
+    47658u32 => "myaep",
+    47659u32 => "myael",
+    47660u32 => "myaem",
+    47661u32 => "myaep",
+    47662u32 => "myaep",
+    47663u32 => "myaet",
+    47664u32 => "myaet",
+    47665u32 => "myaeng",
+    47666u32 => "myaet",
+    47667u32 => "myaet",
+    47668u32 => "myaek",
+    47669u32 => "myaet",
+    47670u32 => "myaep",
+    47671u32 => "myaet",
+    47672u32 => "meo",
+    47673u32 => "meok",
+    47674u32 => "meok",
+    47675u32 => "meok",
+    47676u32 => "meon",
+    47677u32 => "meon",
+    47678u32 => "meon",
+    47679u32 => "meot",
+    47680u32 => "meol",
+    47681u32 => "meok",
+    47682u32 => "meom",
+    47683u32 => "meol",
+    47684u32 => "meol",
+    47685u32 => "meol",
+    47686u32 => "meop",
+    47687u32 => "meol",
+    47688u32 => "meom",
+    47689u32 => "meop",
+    47690u32 => "meop",
+    47691u32 => "meot",
+    47692u32 => "meot",
+    47693u32 => "meong",
+    47694u32 => "meot",
+    47695u32 => "meot",
+    47696u32 => "meok",
+    47697u32 => "meot",
+    47698u32 => "meop",
+    47699u32 => "meot",
+    47700u32 => "me",
+    47701u32 => "mek",
+    47702u32 => "mek",
+    47703u32 => "mek",
+    47704u32 => "men",
+    47705u32 => "men",
+    47706u32 => "men",
+    47707u32 => "met",
+    47708u32 => "mel",
+    47709u32 => "mek",
+    47710u32 => "mem",
+    47711u32 => "mel",
+    47712u32 => "mel",
+    47713u32 => "mel",
+    47714u32 => "mep",
+    47715u32 => "mel",
+    47716u32 => "mem",
+    47717u32 => "mep",
+    47718u32 => "mep",
+    47719u32 => "met",
+    47720u32 => "met",
+    47721u32 => "meng",
+    47722u32 => "met",
+    47723u32 => "met",
+    47724u32 => "mek",
+    47725u32 => "met",
+    47726u32 => "mep",
+    47727u32 => "met",
+    47728u32 => "myeo",
+    47729u32 => "myeok",
+    47730u32 => "myeok",
+    47731u32 => "myeok",
+    47732u32 => "myeon",
+    47733u32 => "myeon",
+    47734u32 => "myeon",
+    47735u32 => "myeot",
+    47736u32 => "myeol",
+    47737u32 => "myeok",
+    47738u32 => "myeom",
+    47739u32 => "myeol",
+    47740u32 => "myeol",
+    47741u32 => "myeol",
+    47742u32 => "myeop",
+    47743u32 => "myeol",
+    47744u32 => "myeom",
+    47745u32 => "myeop",
+    47746u32 => "myeop",
+    47747u32 => "myeot",
+    47748u32 => "myeot",
+    47749u32 => "myeong",
+    47750u32 => "myeot",
+    47751u32 => "myeot",
+    47752u32 => "myeok",
+    47753u32 => "myeot",
+    47754u32 => "myeop",
+    47755u32 => "myeot",
+    47756u32 => "mye",
+    47757u32 => "myek",
+    47758u32 => "myek",
+    47759u32 => "myek",
+    47760u32 => "myen",
+    47761u32 => "myen",
+    47762u32 => "myen",
+    47763u32 => "myet",
+    47764u32 => "myel",
+    47765u32 => "myek",
+    47766u32 => "myem",
+    47767u32 => "myel",
+    47768u32 => "myel",
+    47769u32 => "myel",
+    47770u32 => "myep",
+    47771u32 => "myel",
+    47772u32 => "myem",
+    47773u32 => "myep",
+    47774u32 => "myep",
+    47775u32 => "myet",
+    47776u32 => "myet",
+    47777u32 => "myeng",
+    47778u32 => "myet",
+    47779u32 => "myet",
+    47780u32 => "myek",
+    47781u32 => "myet",
+    47782u32 => "myep",
+    47783u32 => "myet",
+    47784u32 => "mo",
+    47785u32 => "mok",
+    47786u32 => "mok",
+    47787u32 => "mok",
+    47788u32 => "mon",
+    47789u32 => "mon",
+    47790u32 => "mon",
+    47791u32 => "mot",
+    47792u32 => "mol",
+    47793u32 => "mok",
+    47794u32 => "mom",
+    47795u32 => "mol",
+    47796u32 => "mol",
+    47797u32 => "mol",
+    47798u32 => "mop",
+    47799u32 => "mol",
+    47800u32 => "mom",
+    47801u32 => "mop",
+    47802u32 => "mop",
+    47803u32 => "mot",
+    47804u32 => "mot",
+    47805u32 => "mong",
+    47806u32 => "mot",
+    47807u32 => "mot",
+    47808u32 => "mok",
+    47809u32 => "mot",
+    47810u32 => "mop",
+    47811u32 => "mot",
+    47812u32 => "mwa",
+    47813u32 => "mwak",
+    47814u32 => "mwak",
+    47815u32 => "mwak",
+    47816u32 => "mwan",
+    47817u32 => "mwan",
+    47818u32 => "mwan",
+    47819u32 => "mwat",
+    47820u32 => "mwal",
+    47821u32 => "mwak",
+    47822u32 => "mwam",
+    47823u32 => "mwal",
+    47824u32 => "mwal",
+    47825u32 => "mwal",
+    47826u32 => "mwap",
+    47827u32 => "mwal",
+    47828u32 => "mwam",
+    47829u32 => "mwap",
+    47830u32 => "mwap",
+    47831u32 => "mwat",
+    47832u32 => "mwat",
+    47833u32 => "mwang",
+    47834u32 => "mwat",
+    47835u32 => "mwat",
+    47836u32 => "mwak",
+    47837u32 => "mwat",
+    47838u32 => "mwap",
+    47839u32 => "mwat",
+    47840u32 => "mwae",
+    47841u32 => "mwaek",
+    47842u32 => "mwaek",
+    47843u32 => "mwaek",
+    47844u32 => "mwaen",
+    47845u32 => "mwaen",
+    47846u32 => "mwaen",
+    47847u32 => "mwaet",
+    47848u32 => "mwael",
+    47849u32 => "mwaek",
+    47850u32 => "mwaem",
+    47851u32 => "mwael",
+    47852u32 => "mwael",
+    47853u32 => "mwael",
+    47854u32 => "mwaep",
+    47855u32 => "mwael",
+    47856u32 => "mwaem",
+    47857u32 => "mwaep",
+    47858u32 => "mwaep",
+    47859u32 => "mwaet",
+    47860u32 => "mwaet",
+    47861u32 => "mwaeng",
+    47862u32 => "mwaet",
+    47863u32 => "mwaet",
+    47864u32 => "mwaek",
+    47865u32 => "mwaet",
+    47866u32 => "mwaep",
+    47867u32 => "mwaet",
+    47868u32 => "moe",
+    47869u32 => "moek",
+    47870u32 => "moek",
+    47871u32 => "moek",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BB: phf::Map<u32, &str> = phf_map!{
+    47872u32 => "moen",
+    47873u32 => "moen",
+    47874u32 => "moen",
+    47875u32 => "moet",
+    47876u32 => "moel",
+    47877u32 => "moek",
+    47878u32 => "moem",
+    47879u32 => "moel",
+    47880u32 => "moel",
+    47881u32 => "moel",
+    47882u32 => "moep",
+    47883u32 => "moel",
+    47884u32 => "moem",
+    47885u32 => "moep",
+    47886u32 => "moep",
+    47887u32 => "moet",
+    47888u32 => "moet",
+    47889u32 => "moeng",
+    47890u32 => "moet",
+    47891u32 => "moet",
+    47892u32 => "moek",
+    47893u32 => "moet",
+    47894u32 => "moep",
+    47895u32 => "moet",
+    47896u32 => "myo",
+    47897u32 => "myok",
+    47898u32 => "myok",
+    47899u32 => "myok",
+    47900u32 => "myon",
+    47901u32 => "myon",
+    47902u32 => "myon",
+    47903u32 => "myot",
+    47904u32 => "myol",
+    47905u32 => "myok",
+    47906u32 => "myom",
+    47907u32 => "myol",
+    47908u32 => "myol",
+    47909u32 => "myol",
+    47910u32 => "myop",
+    47911u32 => "myol",
+    47912u32 => "myom",
+    47913u32 => "myop",
+    47914u32 => "myop",
+    47915u32 => "myot",
+    47916u32 => "myot",
+    47917u32 => "myong",
+    47918u32 => "myot",
+    47919u32 => "myot",
+    47920u32 => "myok",
+    47921u32 => "myot",
+    47922u32 => "myop",
+    47923u32 => "myot",
+    47924u32 => "mu",
+    47925u32 => "muk",
+    47926u32 => "muk",
+    47927u32 => "muk",
+    47928u32 => "mun",
+    47929u32 => "mun",
+    47930u32 => "mun",
+    47931u32 => "mut",
+    47932u32 => "mul",
+    47933u32 => "muk",
+    47934u32 => "mum",
+    47935u32 => "mul",
+    47936u32 => "mul",
+    47937u32 => "mul",
+    47938u32 => "mup",
+    47939u32 => "mul",
+    47940u32 => "mum",
+    47941u32 => "mup",
+    47942u32 => "mup",
+    47943u32 => "mut",
+    47944u32 => "mut",
+    47945u32 => "mung",
+    47946u32 => "mut",
+    47947u32 => "mut",
+    47948u32 => "muk",
+    47949u32 => "mut",
+    47950u32 => "mup",
+    47951u32 => "mut",
+    47952u32 => "mwo",
+    47953u32 => "mwok",
+    47954u32 => "mwok",
+    47955u32 => "mwok",
+    47956u32 => "mwon",
+    47957u32 => "mwon",
+    47958u32 => "mwon",
+    47959u32 => "mwot",
+    47960u32 => "mwol",
+    47961u32 => "mwok",
+    47962u32 => "mwom",
+    47963u32 => "mwol",
+    47964u32 => "mwol",
+    47965u32 => "mwol",
+    47966u32 => "mwop",
+    47967u32 => "mwol",
+    47968u32 => "mwom",
+    47969u32 => "mwop",
+    47970u32 => "mwop",
+    47971u32 => "mwot",
+    47972u32 => "mwot",
+    47973u32 => "mwong",
+    47974u32 => "mwot",
+    47975u32 => "mwot",
+    47976u32 => "mwok",
+    47977u32 => "mwot",
+    47978u32 => "mwop",
+    47979u32 => "mwot",
+    47980u32 => "mwe",
+    47981u32 => "mwek",
+    47982u32 => "mwek",
+    47983u32 => "mwek",
+    47984u32 => "mwen",
+    47985u32 => "mwen",
+    47986u32 => "mwen",
+    47987u32 => "mwet",
+    47988u32 => "mwel",
+    47989u32 => "mwek",
+    47990u32 => "mwem",
+    47991u32 => "mwel",
+    47992u32 => "mwel",
+    47993u32 => "mwel",
+    47994u32 => "mwep",
+    47995u32 => "mwel",
+    47996u32 => "mwem",
+    47997u32 => "mwep",
+    47998u32 => "mwep",
+    47999u32 => "mwet",
+    48000u32 => "mwet",
+    48001u32 => "mweng",
+    48002u32 => "mwet",
+    48003u32 => "mwet",
+    48004u32 => "mwek",
+    48005u32 => "mwet",
+    48006u32 => "mwep",
+    48007u32 => "mwet",
+    48008u32 => "mwi",
+    48009u32 => "mwik",
+    48010u32 => "mwik",
+    48011u32 => "mwik",
+    48012u32 => "mwin",
+    48013u32 => "mwin",
+    48014u32 => "mwin",
+    48015u32 => "mwit",
+    48016u32 => "mwil",
+    48017u32 => "mwik",
+    48018u32 => "mwim",
+    48019u32 => "mwil",
+    48020u32 => "mwil",
+    48021u32 => "mwil",
+    48022u32 => "mwip",
+    48023u32 => "mwil",
+    48024u32 => "mwim",
+    48025u32 => "mwip",
+    48026u32 => "mwip",
+    48027u32 => "mwit",
+    48028u32 => "mwit",
+    48029u32 => "mwing",
+    48030u32 => "mwit",
+    48031u32 => "mwit",
+    48032u32 => "mwik",
+    48033u32 => "mwit",
+    48034u32 => "mwip",
+    48035u32 => "mwit",
+    48036u32 => "myu",
+    48037u32 => "myuk",
+    48038u32 => "myuk",
+    48039u32 => "myuk",
+    48040u32 => "myun",
+    48041u32 => "myun",
+    48042u32 => "myun",
+    48043u32 => "myut",
+    48044u32 => "myul",
+    48045u32 => "myuk",
+    48046u32 => "myum",
+    48047u32 => "myul",
+    48048u32 => "myul",
+    48049u32 => "myul",
+    48050u32 => "myup",
+    48051u32 => "myul",
+    48052u32 => "myum",
+    48053u32 => "myup",
+    48054u32 => "myup",
+    48055u32 => "myut",
+    48056u32 => "myut",
+    48057u32 => "myung",
+    48058u32 => "myut",
+    48059u32 => "myut",
+    48060u32 => "myuk",
+    48061u32 => "myut",
+    48062u32 => "myup",
+    48063u32 => "myut",
+    48064u32 => "meu",
+    48065u32 => "meuk",
+    48066u32 => "meuk",
+    48067u32 => "meuk",
+    48068u32 => "meun",
+    48069u32 => "meun",
+    48070u32 => "meun",
+    48071u32 => "meut",
+    48072u32 => "meul",
+    48073u32 => "meuk",
+    48074u32 => "meum",
+    48075u32 => "meul",
+    48076u32 => "meul",
+    48077u32 => "meul",
+    48078u32 => "meup",
+    48079u32 => "meul",
+    48080u32 => "meum",
+    48081u32 => "meup",
+    48082u32 => "meup",
+    48083u32 => "meut",
+    48084u32 => "meut",
+    48085u32 => "meung",
+    48086u32 => "meut",
+    48087u32 => "meut",
+    48088u32 => "meuk",
+    48089u32 => "meut",
+    48090u32 => "meup",
+    48091u32 => "meut",
+    48092u32 => "mui",
+    48093u32 => "muik",
+    48094u32 => "muik",
+    48095u32 => "muik",
+    48096u32 => "muin",
+    48097u32 => "muin",
+    48098u32 => "muin",
+    48099u32 => "muit",
+    48100u32 => "muil",
+    48101u32 => "muik",
+    48102u32 => "muim",
+    48103u32 => "muil",
+    48104u32 => "muil",
+    48105u32 => "muil",
+    48106u32 => "muip",
+    48107u32 => "muil",
+    48108u32 => "muim",
+    48109u32 => "muip",
+    48110u32 => "muip",
+    48111u32 => "muit",
+    48112u32 => "muit",
+    48113u32 => "muing",
+    48114u32 => "muit",
+    48115u32 => "muit",
+    48116u32 => "muik",
+    48117u32 => "muit",
+    48118u32 => "muip",
+    48119u32 => "muit",
+    48120u32 => "mi",
+    48121u32 => "mik",
+    48122u32 => "mik",
+    48123u32 => "mik",
+    48124u32 => "min",
+    48125u32 => "min",
+    48126u32 => "min",
+    48127u32 => "mit",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BC: phf::Map<u32, &str> = phf_map!{
+    48128u32 => "mil",
+    48129u32 => "mik",
+    48130u32 => "mim",
+    48131u32 => "mil",
+    48132u32 => "mil",
+    48133u32 => "mil",
+    48134u32 => "mip",
+    48135u32 => "mil",
+    48136u32 => "mim",
+    48137u32 => "mip",
+    48138u32 => "mip",
+    48139u32 => "mit",
+    48140u32 => "mit",
+    48141u32 => "ming",
+    48142u32 => "mit",
+    48143u32 => "mit",
+    48144u32 => "mik",
+    48145u32 => "mit",
+    48146u32 => "mip",
+    48147u32 => "mit",
+    48148u32 => "ba",
+    48149u32 => "bak",
+    48150u32 => "bak",
+    48151u32 => "bak",
+    48152u32 => "ban",
+    48153u32 => "ban",
+    48154u32 => "ban",
+    48155u32 => "bat",
+    48156u32 => "bal",
+    48157u32 => "bak",
+    48158u32 => "bam",
+    48159u32 => "bal",
+    48160u32 => "bal",
+    48161u32 => "bal",
+    48162u32 => "bap",
+    48163u32 => "bal",
+    48164u32 => "bam",
+    48165u32 => "bap",
+    48166u32 => "bap",
+    48167u32 => "bat",
+    48168u32 => "bat",
+    48169u32 => "bang",
+    48170u32 => "bat",
+    48171u32 => "bat",
+    48172u32 => "bak",
+    48173u32 => "bat",
+    48174u32 => "bap",
+    48175u32 => "bat",
+    48176u32 => "bae",
+    48177u32 => "baek",
+    48178u32 => "baek",
+    48179u32 => "baek",
+    48180u32 => "baen",
+    48181u32 => "baen",
+    48182u32 => "baen",
+    48183u32 => "baet",
+    48184u32 => "bael",
+    48185u32 => "baek",
+    48186u32 => "baem",
+    48187u32 => "bael",
+    48188u32 => "bael",
+    48189u32 => "bael",
+    48190u32 => "baep",
+    48191u32 => "bael",
+    48192u32 => "baem",
+    48193u32 => "baep",
+    48194u32 => "baep",
+    48195u32 => "baet",
+    48196u32 => "baet",
+    48197u32 => "baeng",
+    48198u32 => "baet",
+    48199u32 => "baet",
+    48200u32 => "baek",
+    48201u32 => "baet",
+    48202u32 => "baep",
+    48203u32 => "baet",
+    48204u32 => "bya",
+    48205u32 => "byak",
+    48206u32 => "byak",
+    48207u32 => "byak",
+    48208u32 => "byan",
+    48209u32 => "byan",
+    48210u32 => "byan",
+    48211u32 => "byat",
+    48212u32 => "byal",
+    48213u32 => "byak",
+    48214u32 => "byam",
+    48215u32 => "byal",
+    48216u32 => "byal",
+    48217u32 => "byal",
+    48218u32 => "byap",
+    48219u32 => "byal",
+    48220u32 => "byam",
+    48221u32 => "byap",
+    48222u32 => "byap",
+    48223u32 => "byat",
+    48224u32 => "byat",
+    48225u32 => "byang",
+    48226u32 => "byat",
+    48227u32 => "byat",
+    48228u32 => "byak",
+    48229u32 => "byat",
+    48230u32 => "byap",
+    48231u32 => "byat",
+    48232u32 => "byae",
+    48233u32 => "byaek",
+    48234u32 => "byaek",
+    48235u32 => "byaek",
+    48236u32 => "byaen",
+    48237u32 => "byaen",
+    48238u32 => "byaen",
+    48239u32 => "byaet",
+    48240u32 => "byael",
+    48241u32 => "byaek",
+    48242u32 => "byaem",
+    48243u32 => "byael",
+    48244u32 => "byael",
+    48245u32 => "byael",
+    48246u32 => "byaep",
+    48247u32 => "byael",
+    48248u32 => "byaem",
+    48249u32 => "byaep",
+    48250u32 => "byaep",
+    48251u32 => "byaet",
+    48252u32 => "byaet",
+    48253u32 => "byaeng",
+    48254u32 => "byaet",
+    48255u32 => "byaet",
+    48256u32 => "byaek",
+    48257u32 => "byaet",
+    48258u32 => "byaep",
+    48259u32 => "byaet",
+    48260u32 => "beo",
+    48261u32 => "beok",
+    48262u32 => "beok",
+    48263u32 => "beok",
+    48264u32 => "beon",
+    48265u32 => "beon",
+    48266u32 => "beon",
+    48267u32 => "beot",
+    48268u32 => "beol",
+    48269u32 => "beok",
+    48270u32 => "beom",
+    48271u32 => "beol",
+    48272u32 => "beol",
+    48273u32 => "beol",
+    48274u32 => "beop",
+    48275u32 => "beol",
+    48276u32 => "beom",
+    48277u32 => "beop",
+    48278u32 => "beop",
+    48279u32 => "beot",
+    48280u32 => "beot",
+    48281u32 => "beong",
+    48282u32 => "beot",
+    48283u32 => "beot",
+    48284u32 => "beok",
+    48285u32 => "beot",
+    48286u32 => "beop",
+    48287u32 => "beot",
+    48288u32 => "be",
+    48289u32 => "bek",
+    48290u32 => "bek",
+    48291u32 => "bek",
+    48292u32 => "ben",
+    48293u32 => "ben",
+    48294u32 => "ben",
+    48295u32 => "bet",
+    48296u32 => "bel",
+    48297u32 => "bek",
+    48298u32 => "bem",
+    48299u32 => "bel",
+    48300u32 => "bel",
+    48301u32 => "bel",
+    48302u32 => "bep",
+    48303u32 => "bel",
+    48304u32 => "bem",
+    48305u32 => "bep",
+    48306u32 => "bep",
+    48307u32 => "bet",
+    48308u32 => "bet",
+    48309u32 => "beng",
+    48310u32 => "bet",
+    48311u32 => "bet",
+    48312u32 => "bek",
+    48313u32 => "bet",
+    48314u32 => "bep",
+    48315u32 => "bet",
+    48316u32 => "byeo",
+    48317u32 => "byeok",
+    48318u32 => "byeok",
+    48319u32 => "byeok",
+    48320u32 => "byeon",
+    48321u32 => "byeon",
+    48322u32 => "byeon",
+    48323u32 => "byeot",
+    48324u32 => "byeol",
+    48325u32 => "byeok",
+    48326u32 => "byeom",
+    48327u32 => "byeol",
+    48328u32 => "byeol",
+    48329u32 => "byeol",
+    48330u32 => "byeop",
+    48331u32 => "byeol",
+    48332u32 => "byeom",
+    48333u32 => "byeop",
+    48334u32 => "byeop",
+    48335u32 => "byeot",
+    48336u32 => "byeot",
+    48337u32 => "byeong",
+    48338u32 => "byeot",
+    48339u32 => "byeot",
+    48340u32 => "byeok",
+    48341u32 => "byeot",
+    48342u32 => "byeop",
+    48343u32 => "byeot",
+    48344u32 => "bye",
+    48345u32 => "byek",
+    48346u32 => "byek",
+    48347u32 => "byek",
+    48348u32 => "byen",
+    48349u32 => "byen",
+    48350u32 => "byen",
+    48351u32 => "byet",
+    48352u32 => "byel",
+    48353u32 => "byek",
+    48354u32 => "byem",
+    48355u32 => "byel",
+    48356u32 => "byel",
+    48357u32 => "byel",
+    48358u32 => "byep",
+    48359u32 => "byel",
+    48360u32 => "byem",
+    48361u32 => "byep",
+    48362u32 => "byep",
+    48363u32 => "byet",
+    48364u32 => "byet",
+    48365u32 => "byeng",
+    48366u32 => "byet",
+    48367u32 => "byet",
+    48368u32 => "byek",
+    48369u32 => "byet",
+    48370u32 => "byep",
+    48371u32 => "byet",
+    48372u32 => "bo",
+    48373u32 => "bok",
+    48374u32 => "bok",
+    48375u32 => "bok",
+    48376u32 => "bon",
+    48377u32 => "bon",
+    48378u32 => "bon",
+    48379u32 => "bot",
+    48380u32 => "bol",
+    48381u32 => "bok",
+    48382u32 => "bom",
+    48383u32 => "bol",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BD: phf::Map<u32, &str> = phf_map!{
+    48384u32 => "bol",
+    48385u32 => "bol",
+    48386u32 => "bop",
+    48387u32 => "bol",
+    48388u32 => "bom",
+    48389u32 => "bop",
+    48390u32 => "bop",
+    48391u32 => "bot",
+    48392u32 => "bot",
+    48393u32 => "bong",
+    48394u32 => "bot",
+    48395u32 => "bot",
+    48396u32 => "bok",
+    48397u32 => "bot",
+    48398u32 => "bop",
+    48399u32 => "bot",
+    48400u32 => "bwa",
+    48401u32 => "bwak",
+    48402u32 => "bwak",
+    48403u32 => "bwak",
+    48404u32 => "bwan",
+    48405u32 => "bwan",
+    48406u32 => "bwan",
+    48407u32 => "bwat",
+    48408u32 => "bwal",
+    48409u32 => "bwak",
+    48410u32 => "bwam",
+    48411u32 => "bwal",
+    48412u32 => "bwal",
+    48413u32 => "bwal",
+    48414u32 => "bwap",
+    48415u32 => "bwal",
+    48416u32 => "bwam",
+    48417u32 => "bwap",
+    48418u32 => "bwap",
+    48419u32 => "bwat",
+    48420u32 => "bwat",
+    48421u32 => "bwang",
+    48422u32 => "bwat",
+    48423u32 => "bwat",
+    48424u32 => "bwak",
+    48425u32 => "bwat",
+    48426u32 => "bwap",
+    48427u32 => "bwat",
+    48428u32 => "bwae",
+    48429u32 => "bwaek",
+    48430u32 => "bwaek",
+    48431u32 => "bwaek",
+    48432u32 => "bwaen",
+    48433u32 => "bwaen",
+    48434u32 => "bwaen",
+    48435u32 => "bwaet",
+    48436u32 => "bwael",
+    48437u32 => "bwaek",
+    48438u32 => "bwaem",
+    48439u32 => "bwael",
+    48440u32 => "bwael",
+    48441u32 => "bwael",
+    48442u32 => "bwaep",
+    48443u32 => "bwael",
+    48444u32 => "bwaem",
+    48445u32 => "bwaep",
+    48446u32 => "bwaep",
+    48447u32 => "bwaet",
+    48448u32 => "bwaet",
+    48449u32 => "bwaeng",
+    48450u32 => "bwaet",
+    48451u32 => "bwaet",
+    48452u32 => "bwaek",
+    48453u32 => "bwaet",
+    48454u32 => "bwaep",
+    48455u32 => "bwaet",
+    48456u32 => "boe",
+    48457u32 => "boek",
+    48458u32 => "boek",
+    48459u32 => "boek",
+    48460u32 => "boen",
+    48461u32 => "boen",
+    48462u32 => "boen",
+    48463u32 => "boet",
+    48464u32 => "boel",
+    48465u32 => "boek",
+    48466u32 => "boem",
+    48467u32 => "boel",
+    48468u32 => "boel",
+    48469u32 => "boel",
+    48470u32 => "boep",
+    48471u32 => "boel",
+    48472u32 => "boem",
+    48473u32 => "boep",
+    48474u32 => "boep",
+    48475u32 => "boet",
+    48476u32 => "boet",
+    48477u32 => "boeng",
+    48478u32 => "boet",
+    48479u32 => "boet",
+    48480u32 => "boek",
+    48481u32 => "boet",
+    48482u32 => "boep",
+    48483u32 => "boet",
+    48484u32 => "byo",
+    48485u32 => "byok",
+    48486u32 => "byok",
+    48487u32 => "byok",
+    48488u32 => "byon",
+    48489u32 => "byon",
+    48490u32 => "byon",
+    48491u32 => "byot",
+    48492u32 => "byol",
+    48493u32 => "byok",
+    48494u32 => "byom",
+    48495u32 => "byol",
+    48496u32 => "byol",
+    48497u32 => "byol",
+    48498u32 => "byop",
+    48499u32 => "byol",
+    48500u32 => "byom",
+    48501u32 => "byop",
+    48502u32 => "byop",
+    48503u32 => "byot",
+    48504u32 => "byot",
+    48505u32 => "byong",
+    48506u32 => "byot",
+    48507u32 => "byot",
+    48508u32 => "byok",
+    48509u32 => "byot",
+    48510u32 => "byop",
+    48511u32 => "byot",
+    48512u32 => "bu",
+    48513u32 => "buk",
+    48514u32 => "buk",
+    48515u32 => "buk",
+    48516u32 => "bun",
+    48517u32 => "bun",
+    48518u32 => "bun",
+    48519u32 => "but",
+    48520u32 => "bul",
+    48521u32 => "buk",
+    48522u32 => "bum",
+    48523u32 => "bul",
+    48524u32 => "bul",
+    48525u32 => "bul",
+    48526u32 => "bup",
+    48527u32 => "bul",
+    48528u32 => "bum",
+    48529u32 => "bup",
+    48530u32 => "bup",
+    48531u32 => "but",
+    48532u32 => "but",
+    48533u32 => "bung",
+    48534u32 => "but",
+    48535u32 => "but",
+    48536u32 => "buk",
+    48537u32 => "but",
+    48538u32 => "bup",
+    48539u32 => "but",
+    48540u32 => "bwo",
+    48541u32 => "bwok",
+    48542u32 => "bwok",
+    48543u32 => "bwok",
+    48544u32 => "bwon",
+    48545u32 => "bwon",
+    48546u32 => "bwon",
+    48547u32 => "bwot",
+    48548u32 => "bwol",
+    48549u32 => "bwok",
+    48550u32 => "bwom",
+    48551u32 => "bwol",
+    48552u32 => "bwol",
+    48553u32 => "bwol",
+    48554u32 => "bwop",
+    48555u32 => "bwol",
+    48556u32 => "bwom",
+    48557u32 => "bwop",
+    48558u32 => "bwop",
+    48559u32 => "bwot",
+    48560u32 => "bwot",
+    48561u32 => "bwong",
+    48562u32 => "bwot",
+    48563u32 => "bwot",
+    48564u32 => "bwok",
+    48565u32 => "bwot",
+    48566u32 => "bwop",
+    48567u32 => "bwot",
+    48568u32 => "bwe",
+    48569u32 => "bwek",
+    48570u32 => "bwek",
+    48571u32 => "bwek",
+    48572u32 => "bwen",
+    48573u32 => "bwen",
+    48574u32 => "bwen",
+    48575u32 => "bwet",
+    48576u32 => "bwel",
+    48577u32 => "bwek",
+    48578u32 => "bwem",
+    48579u32 => "bwel",
+    48580u32 => "bwel",
+    48581u32 => "bwel",
+    48582u32 => "bwep",
+    48583u32 => "bwel",
+    48584u32 => "bwem",
+    48585u32 => "bwep",
+    48586u32 => "bwep",
+    48587u32 => "bwet",
+    48588u32 => "bwet",
+    48589u32 => "bweng",
+    48590u32 => "bwet",
+    48591u32 => "bwet",
+    48592u32 => "bwek",
+    48593u32 => "bwet",
+    48594u32 => "bwep",
+    48595u32 => "bwet",
+    48596u32 => "bwi",
+    48597u32 => "bwik",
+    48598u32 => "bwik",
+    48599u32 => "bwik",
+    48600u32 => "bwin",
+    48601u32 => "bwin",
+    48602u32 => "bwin",
+    48603u32 => "bwit",
+    48604u32 => "bwil",
+    48605u32 => "bwik",
+    48606u32 => "bwim",
+    48607u32 => "bwil",
+    48608u32 => "bwil",
+    48609u32 => "bwil",
+    48610u32 => "bwip",
+    48611u32 => "bwil",
+    48612u32 => "bwim",
+    48613u32 => "bwip",
+    48614u32 => "bwip",
+    48615u32 => "bwit",
+    48616u32 => "bwit",
+    48617u32 => "bwing",
+    48618u32 => "bwit",
+    48619u32 => "bwit",
+    48620u32 => "bwik",
+    48621u32 => "bwit",
+    48622u32 => "bwip",
+    48623u32 => "bwit",
+    48624u32 => "byu",
+    48625u32 => "byuk",
+    48626u32 => "byuk",
+    48627u32 => "byuk",
+    48628u32 => "byun",
+    48629u32 => "byun",
+    48630u32 => "byun",
+    48631u32 => "byut",
+    48632u32 => "byul",
+    48633u32 => "byuk",
+    48634u32 => "byum",
+    48635u32 => "byul",
+    48636u32 => "byul",
+    48637u32 => "byul",
+    48638u32 => "byup",
+    48639u32 => "byul",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BE: phf::Map<u32, &str> = phf_map!{
+    48640u32 => "byum",
+    48641u32 => "byup",
+    48642u32 => "byup",
+    48643u32 => "byut",
+    48644u32 => "byut",
+    48645u32 => "byung",
+    48646u32 => "byut",
+    48647u32 => "byut",
+    48648u32 => "byuk",
+    48649u32 => "byut",
+    48650u32 => "byup",
+    48651u32 => "byut",
+    48652u32 => "beu",
+    48653u32 => "beuk",
+    48654u32 => "beuk",
+    48655u32 => "beuk",
+    48656u32 => "beun",
+    48657u32 => "beun",
+    48658u32 => "beun",
+    48659u32 => "beut",
+    48660u32 => "beul",
+    48661u32 => "beuk",
+    48662u32 => "beum",
+    48663u32 => "beul",
+    48664u32 => "beul",
+    48665u32 => "beul",
+    48666u32 => "beup",
+    48667u32 => "beul",
+    48668u32 => "beum",
+    48669u32 => "beup",
+    48670u32 => "beup",
+    48671u32 => "beut",
+    48672u32 => "beut",
+    48673u32 => "beung",
+    48674u32 => "beut",
+    48675u32 => "beut",
+    48676u32 => "beuk",
+    48677u32 => "beut",
+    48678u32 => "beup",
+    48679u32 => "beut",
+    48680u32 => "bui",
+    48681u32 => "buik",
+    48682u32 => "buik",
+    48683u32 => "buik",
+    48684u32 => "buin",
+    48685u32 => "buin",
+    48686u32 => "buin",
+    48687u32 => "buit",
+    48688u32 => "buil",
+    48689u32 => "buik",
+    48690u32 => "buim",
+    48691u32 => "buil",
+    48692u32 => "buil",
+    48693u32 => "buil",
+    48694u32 => "buip",
+    48695u32 => "buil",
+    48696u32 => "buim",
+    48697u32 => "buip",
+    48698u32 => "buip",
+    48699u32 => "buit",
+    48700u32 => "buit",
+    48701u32 => "buing",
+    48702u32 => "buit",
+    48703u32 => "buit",
+    48704u32 => "buik",
+    48705u32 => "buit",
+    48706u32 => "buip",
+    48707u32 => "buit",
+    48708u32 => "bi",
+    48709u32 => "bik",
+    48710u32 => "bik",
+    48711u32 => "bik",
+    48712u32 => "bin",
+    48713u32 => "bin",
+    48714u32 => "bin",
+    48715u32 => "bit",
+    48716u32 => "bil",
+    48717u32 => "bik",
+    48718u32 => "bim",
+    48719u32 => "bil",
+    48720u32 => "bil",
+    48721u32 => "bil",
+    48722u32 => "bip",
+    48723u32 => "bil",
+    48724u32 => "bim",
+    48725u32 => "bip",
+    48726u32 => "bip",
+    48727u32 => "bit",
+    48728u32 => "bit",
+    48729u32 => "bing",
+    48730u32 => "bit",
+    48731u32 => "bit",
+    48732u32 => "bik",
+    48733u32 => "bit",
+    48734u32 => "bip",
+    48735u32 => "bit",
+    48736u32 => "ppa",
+    48737u32 => "ppak",
+    48738u32 => "ppak",
+    48739u32 => "ppak",
+    48740u32 => "ppan",
+    48741u32 => "ppan",
+    48742u32 => "ppan",
+    48743u32 => "ppat",
+    48744u32 => "ppal",
+    48745u32 => "ppak",
+    48746u32 => "ppam",
+    48747u32 => "ppal",
+    48748u32 => "ppal",
+    48749u32 => "ppal",
+    48750u32 => "ppap",
+    48751u32 => "ppal",
+    48752u32 => "ppam",
+    48753u32 => "ppap",
+    48754u32 => "ppap",
+    48755u32 => "ppat",
+    48756u32 => "ppat",
+    48757u32 => "ppang",
+    48758u32 => "ppat",
+    48759u32 => "ppat",
+    48760u32 => "ppak",
+    48761u32 => "ppat",
+    48762u32 => "ppap",
+    48763u32 => "ppat",
+    48764u32 => "ppae",
+    48765u32 => "ppaek",
+    48766u32 => "ppaek",
+    48767u32 => "ppaek",
+    48768u32 => "ppaen",
+    48769u32 => "ppaen",
+    48770u32 => "ppaen",
+    48771u32 => "ppaet",
+    48772u32 => "ppael",
+    48773u32 => "ppaek",
+    48774u32 => "ppaem",
+    48775u32 => "ppael",
+    48776u32 => "ppael",
+    48777u32 => "ppael",
+    48778u32 => "ppaep",
+    48779u32 => "ppael",
+    48780u32 => "ppaem",
+    48781u32 => "ppaep",
+    48782u32 => "ppaep",
+    48783u32 => "ppaet",
+    48784u32 => "ppaet",
+    48785u32 => "ppaeng",
+    48786u32 => "ppaet",
+    48787u32 => "ppaet",
+    48788u32 => "ppaek",
+    48789u32 => "ppaet",
+    48790u32 => "ppaep",
+    48791u32 => "ppaet",
+    48792u32 => "ppya",
+    48793u32 => "ppyak",
+    48794u32 => "ppyak",
+    48795u32 => "ppyak",
+    48796u32 => "ppyan",
+    48797u32 => "ppyan",
+    48798u32 => "ppyan",
+    48799u32 => "ppyat",
+    48800u32 => "ppyal",
+    48801u32 => "ppyak",
+    48802u32 => "ppyam",
+    48803u32 => "ppyal",
+    48804u32 => "ppyal",
+    48805u32 => "ppyal",
+    48806u32 => "ppyap",
+    48807u32 => "ppyal",
+    48808u32 => "ppyam",
+    48809u32 => "ppyap",
+    48810u32 => "ppyap",
+    48811u32 => "ppyat",
+    48812u32 => "ppyat",
+    48813u32 => "ppyang",
+    48814u32 => "ppyat",
+    48815u32 => "ppyat",
+    48816u32 => "ppyak",
+    48817u32 => "ppyat",
+    48818u32 => "ppyap",
+    48819u32 => "ppyat",
+    48820u32 => "ppyae",
+    48821u32 => "ppyaek",
+    48822u32 => "ppyaek",
+    48823u32 => "ppyaek",
+    48824u32 => "ppyaen",
+    48825u32 => "ppyaen",
+    48826u32 => "ppyaen",
+    48827u32 => "ppyaet",
+    48828u32 => "ppyael",
+    48829u32 => "ppyaek",
+    48830u32 => "ppyaem",
+    48831u32 => "ppyael",
+    48832u32 => "ppyael",
+    48833u32 => "ppyael",
+    48834u32 => "ppyaep",
+    48835u32 => "ppyael",
+    48836u32 => "ppyaem",
+    48837u32 => "ppyaep",
+    48838u32 => "ppyaep",
+    48839u32 => "ppyaet",
+    48840u32 => "ppyaet",
+    48841u32 => "ppyaeng",
+    48842u32 => "ppyaet",
+    48843u32 => "ppyaet",
+    48844u32 => "ppyaek",
+    48845u32 => "ppyaet",
+    48846u32 => "ppyaep",
+    48847u32 => "ppyaet",
+    48848u32 => "ppeo",
+    48849u32 => "ppeok",
+    48850u32 => "ppeok",
+    48851u32 => "ppeok",
+    48852u32 => "ppeon",
+    48853u32 => "ppeon",
+    48854u32 => "ppeon",
+    48855u32 => "ppeot",
+    48856u32 => "ppeol",
+    48857u32 => "ppeok",
+    48858u32 => "ppeom",
+    48859u32 => "ppeol",
+    48860u32 => "ppeol",
+    48861u32 => "ppeol",
+    48862u32 => "ppeop",
+    48863u32 => "ppeol",
+    48864u32 => "ppeom",
+    48865u32 => "ppeop",
+    48866u32 => "ppeop",
+    48867u32 => "ppeot",
+    48868u32 => "ppeot",
+    48869u32 => "ppeong",
+    48870u32 => "ppeot",
+    48871u32 => "ppeot",
+    48872u32 => "ppeok",
+    48873u32 => "ppeot",
+    48874u32 => "ppeop",
+    48875u32 => "ppeot",
+    48876u32 => "ppe",
+    48877u32 => "ppek",
+    48878u32 => "ppek",
+    48879u32 => "ppek",
+    48880u32 => "ppen",
+    48881u32 => "ppen",
+    48882u32 => "ppen",
+    48883u32 => "ppet",
+    48884u32 => "ppel",
+    48885u32 => "ppek",
+    48886u32 => "ppem",
+    48887u32 => "ppel",
+    48888u32 => "ppel",
+    48889u32 => "ppel",
+    48890u32 => "ppep",
+    48891u32 => "ppel",
+    48892u32 => "ppem",
+    48893u32 => "ppep",
+    48894u32 => "ppep",
+    48895u32 => "ppet",
+};
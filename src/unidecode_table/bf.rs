@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_BF: phf::Map<u32, &str> = phf_map!{
+    48896u32 => "ppet",
+    48897u32 => "ppeng",
+    48898u32 => "ppet",
+    48899u32 => "ppet",
+    48900u32 => "ppek",
+    48901u32 => "ppet",
+    48902u32 => "ppep",
+    48903u32 => "ppet",
+    48904u32 => "ppyeo",
+    48905u32 => "ppyeok",
+    48906u32 => "ppyeok",
+    48907u32 => "ppyeok",
+    48908u32 => "ppyeon",
+    48909u32 => "ppyeon",
+    48910u32 => "ppyeon",
+    48911u32 => "ppyeot",
+    48912u32 => "ppyeol",
+    48913u32 => "ppyeok",
+    48914u32 => "ppyeom",
+    48915u32 => "ppyeol",
+    48916u32 => "ppyeol",
+    48917u32 => "ppyeol",
+    48918u32 => "ppyeop",
+    48919u32 => "ppyeol",
+    48920u32 => "ppyeom",
+    48921u32 => "ppyeop",
+    48922u32 => "ppyeop",
+    48923u32 => "ppyeot",
+    48924u32 => "ppyeot",
+    48925u32 => "ppyeong",
+    48926u32 => "ppyeot",
+    48927u32 => "ppyeot",
+    48928u32 => "ppyeok",
+    48929u32 => "ppyeot",
+    48930u32 => "ppyeop",
+    48931u32 => "ppyeot",
+    48932u32 => "ppye",
+    48933u32 => "ppyek",
+    48934u32 => "ppyek",
+    48935u32 => "ppyek",
+    48936u32 => "ppyen",
+    48937u32 => "ppyen",
+    48938u32 => "ppyen",
+    48939u32 => "ppyet",
+    48940u32 => "ppyel",
+    48941u32 => "ppyek",
+    48942u32 => "ppyem",
+    48943u32 => "ppyel",
+    48944u32 => "ppyel",
+    48945u32 => "ppyel",
+    48946u32 => "ppyep",
+    48947u32 => "ppyel",
+    48948u32 => "ppyem",
+    48949u32 => "ppyep",
+    48950u32 => "ppyep",
+    48951u32 => "ppyet",
+    48952u32 => "ppyet",
+    48953u32 => "ppyeng",
+    48954u32 => "ppyet",
+    48955u32 => "ppyet",
+    48956u32 => "ppyek",
+    48957u32 => "ppyet",
+    48958u32 => "ppyep",
+    48959u32 => "ppyet",
+    48960u32 => "ppo",
+    48961u32 => "ppok",
+    48962u32 => "ppok",
+    48963u32 => "ppok",
+    48964u32 => "ppon",
+    48965u32 => "ppon",
+    48966u32 => "ppon",
+    48967u32 => "ppot",
+    48968u32 => "ppol",
+    48969u32 => "ppok",
+    48970u32 => "ppom",
+    48971u32 => "ppol",
+    48972u32 => "ppol",
+    48973u32 => "ppol",
+    48974u32 => "ppop",
+    48975u32 => "ppol",
+    48976u32 => "ppom",
+    48977u32 => "ppop",
+    48978u32 => "ppop",
+    48979u32 => "ppot",
+    48980u32 => "ppot",
+    48981u32 => "ppong",
+    48982u32 => "ppot",
+    48983u32 => "ppot",
+    48984u32 => "ppok",
+    48985u32 => "ppot",
+    48986u32 => "ppop",
+    48987u32 => "ppot",
+    48988u32 => "ppwa",
+    48989u32 => "ppwak",
+    48990u32 => "ppwak",
+    48991u32 => "ppwak",
+    48992u32 => "ppwan",
+    48993u32 => "ppwan",
+    48994u32 => "ppwan",
+    48995u32 => "ppwat",
+    48996u32 => "ppwal",
+    48997u32 => "ppwak",
+    48998u32 => "ppwam",
+    48999u32 => "ppwal",
+    49000u32 => "ppwal",
+    49001u32 => "ppwal",
+    49002u32 => "ppwap",
+    49003u32 => "ppwal",
+    49004u32 => "ppwam",
+    49005u32 => "ppwap",
+    49006u32 => "ppwap",
+    49007u32 => "ppwat",
+    49008u32 => "ppwat",
+    49009u32 => "ppwang",
+    49010u32 => "ppwat",
+    49011u32 => "ppwat",
+    49012u32 => "ppwak",
+    49013u32 => "ppwat",
+    49014u32 => "ppwap",
+    49015u32 => "ppwat",
+    49016u32 => "ppwae",
+    49017u32 => "ppwaek",
+    49018u32 => "ppwaek",
+    49019u32 => "ppwaek",
+    49020u32 => "ppwaen",
+    49021u32 => "ppwaen",
+    49022u32 => "ppwaen",
+    49023u32 => "ppwaet",
+    49024u32 => "ppwael",
+    49025u32 => "ppwaek",
+    49026u32 => "ppwaem",
+    49027u32 => "ppwael",
+    49028u32 => "ppwael",
+    49029u32 => "ppwael",
+    49030u32 => "ppwaep",
+    49031u32 => "ppwael",
+    49032u32 => "ppwaem",
+    49033u32 => "ppwaep",
+    49034u32 => "ppwaep",
+    49035u32 => "ppwaet",
+    49036u32 => "ppwaet",
+    49037u32 => "ppwaeng",
+    49038u32 => "ppwaet",
+    49039u32 => "ppwaet",
+    49040u32 => "ppwaek",
+    49041u32 => "ppwaet",
+    49042u32 => "ppwaep",
+    49043u32 => "ppwaet",
+    49044u32 => "ppoe",
+    49045u32 => "ppoek",
+    49046u32 => "ppoek",
+    49047u32 => "ppoek",
+    49048u32 => "ppoen",
+    49049u32 => "ppoen",
+    49050u32 => "ppoen",
+    49051u32 => "ppoet",
+    49052u32 => "ppoel",
+    49053u32 => "ppoek",
+    49054u32 => "ppoem",
+    49055u32 => "ppoel",
+    49056u32 => "ppoel",
+    49057u32 => "ppoel",
+    49058u32 => "ppoep",
+    49059u32 => "ppoel",
+    49060u32 => "ppoem",
+    49061u32 => "ppoep",
+    49062u32 => "ppoep",
+    49063u32 => "ppoet",
+    49064u32 => "ppoet",
+    49065u32 => "ppoeng",
+    49066u32 => "ppoet",
+    49067u32 => "ppoet",
+    49068u32 => "ppoek",
+    49069u32 => "ppoet",
+    49070u32 => "ppoep",
+    49071u32 => "ppoet",
+    49072u32 => "ppyo",
+    49073u32 => "ppyok",
+    49074u32 => "ppyok",
+    49075u32 => "ppyok",
+    49076u32 => "ppyon",
+    49077u32 => "ppyon",
+    49078u32 => "ppyon",
+    49079u32 => "ppyot",
+    49080u32 => "ppyol",
+    49081u32 => "ppyok",
+    49082u32 => "ppyom",
+    49083u32 => "ppyol",
+    49084u32 => "ppyol",
+    49085u32 => "ppyol",
+    49086u32 => "ppyop",
+    49087u32 => "ppyol",
+    49088u32 => "ppyom",
+    49089u32 => "ppyop",
+    49090u32 => "ppyop",
+    49091u32 => "ppyot",
+    49092u32 => "ppyot",
+    49093u32 => "ppyong",
+    49094u32 => "ppyot",
+    49095u32 => "ppyot",
+    49096u32 => "ppyok",
+    49097u32 => "ppyot",
+    49098u32 => "ppyop",
+    49099u32 => "ppyot",
+    49100u32 => "ppu",
+    49101u32 => "ppuk",
+    49102u32 => "ppuk",
+    49103u32 => "ppuk",
+    49104u32 => "ppun",
+    49105u32 => "ppun",
+    49106u32 => "ppun",
+    49107u32 => "pput",
+    49108u32 => "ppul",
+    49109u32 => "ppuk",
+    49110u32 => "ppum",
+    49111u32 => "ppul",
+    49112u32 => "ppul",
+    49113u32 => "ppul",
+    49114u32 => "ppup",
+    49115u32 => "ppul",
+    49116u32 => "ppum",
+    49117u32 => "ppup",
+    49118u32 => "ppup",
+    49119u32 => "pput",
+    49120u32 => "pput",
+    49121u32 => "ppung",
+    49122u32 => "pput",
+    49123u32 => "pput",
+    49124u32 => "ppuk",
+    49125u32 => "pput",
+    49126u32 => "ppup",
+    49127u32 => "pput",
+    49128u32 => "ppwo",
+    49129u32 => "ppwok",
+    49130u32 => "ppwok",
+    49131u32 => "ppwok",
+    49132u32 => "ppwon",
+    49133u32 => "ppwon",
+    49134u32 => "ppwon",
+    49135u32 => "ppwot",
+    49136u32 => "ppwol",
+    49137u32 => "ppwok",
+    49138u32 => "ppwom",
+    49139u32 => "ppwol",
+    49140u32 => "ppwol",
+    49141u32 => "ppwol",
+    49142u32 => "ppwop",
+    49143u32 => "ppwol",
+    49144u32 => "ppwom",
+    49145u32 => "ppwop",
+    49146u32 => "ppwop",
+    49147u32 => "ppwot",
+    49148u32 => "ppwot",
+    49149u32 => "ppwong",
+    49150u32 => "ppwot",
+    49151u32 => "ppwot",
+};
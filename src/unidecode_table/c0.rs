@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_C0: phf::Map<u32, &str> = phf_map!{
+    49152u32 => "ppwok",
+    49153u32 => "ppwot",
+    49154u32 => "ppwop",
+    49155u32 => "ppwot",
+    49156u32 => "ppwe",
+    49157u32 => "ppwek",
+    49158u32 => "ppwek",
+    49159u32 => "ppwek",
+    49160u32 => "ppwen",
+    49161u32 => "ppwen",
+    49162u32 => "ppwen",
+    49163u32 => "ppwet",
+    49164u32 => "ppwel",
+    49165u32 => "ppwek",
+    49166u32 => "ppwem",
+    49167u32 => "ppwel",
+    49168u32 => "ppwel",
+    49169u32 => "ppwel",
+    49170u32 => "ppwep",
+    49171u32 => "ppwel",
+    49172u32 => "ppwem",
+    49173u32 => "ppwep",
+    49174u32 => "ppwep",
+    49175u32 => "ppwet",
+    49176u32 => "ppwet",
+    49177u32 => "ppweng",
+    49178u32 => "ppwet",
+    49179u32 => "ppwet",
+    49180u32 => "ppwek",
+    49181u32 => "ppwet",
+    49182u32 => "ppwep",
+    49183u32 => "ppwet",
+    49184u32 => "ppwi",
+    49185u32 => "ppwik",
+    49186u32 => "ppwik",
+    49187u32 => "ppwik",
+    49188u32 => "ppwin",
+    49189u32 => "ppwin",
+    49190u32 => "ppwin",
+    49191u32 => "ppwit",
+    49192u32 => "ppwil",
+    49193u32 => "ppwik",
+    49194u32 => "ppwim",
+    49195u32 => "ppwil",
+    49196u32 => "ppwil",
+    49197u32 => "ppwil",
+    49198u32 => "ppwip",
+    49199u32 => "ppwil",
+    49200u32 => "ppwim",
+    49201u32 => "ppwip",
+    49202u32 => "ppwip",
+    49203u32 => "ppwit",
+    49204u32 => "ppwit",
+    49205u32 => "ppwing",
+    49206u32 => "ppwit",
+    49207u32 => "ppwit",
+    49208u32 => "ppwik",
+    49209u32 => "ppwit",
+    49210u32 => "ppwip",
+    49211u32 => "ppwit",
+    49212u32 => "ppyu",
+    49213u32 => "ppyuk",
+    49214u32 => "ppyuk",
+    49215u32 => "ppyuk",
+    49216u32 => "ppyun",
+    49217u32 => "ppyun",
+    49218u32 => "ppyun",
+    49219u32 => "ppyut",
+    49220u32 => "ppyul",
+    49221u32 => "ppyuk",
+    49222u32 => "ppyum",
+    49223u32 => "ppyul",
+    49224u32 => "ppyul",
+    49225u32 => "ppyul",
+    49226u32 => "ppyup",
+    49227u32 => "ppyul",
+    49228u32 => "ppyum",
+    49229u32 => "ppyup",
+    49230u32 => "ppyup",
+    49231u32 => "ppyut",
+    49232u32 => "ppyut",
+    49233u32 => "ppyung",
+    49234u32 => "ppyut",
+    49235u32 => "ppyut",
+    49236u32 => "ppyuk",
+    49237u32 => "ppyut",
+    49238u32 => "ppyup",
+    49239u32 => "ppyut",
+    49240u32 => "ppeu",
+    49241u32 => "ppeuk",
+    49242u32 => "ppeuk",
+    49243u32 => "ppeuk",
+    49244u32 => "ppeun",
+    49245u32 => "ppeun",
+    49246u32 => "ppeun",
+    49247u32 => "ppeut",
+    49248u32 => "ppeul",
+    49249u32 => "ppeuk",
+    49250u32 => "ppeum",
+    49251u32 => "ppeul",
+    49252u32 => "ppeul",
+    49253u32 => "ppeul",
+    49254u32 => "ppeup",
+    49255u32 => "ppeul",
+    49256u32 => "ppeum",
+    49257u32 => "ppeup",
+    49258u32 => "ppeup",
+    49259u32 => "ppeut",
+    49260u32 => "ppeut",
+    49261u32 => "ppeung",
+    49262u32 => "ppeut",
+    49263u32 => "ppeut",
+    49264u32 => "ppeuk",
+    49265u32 => "ppeut",
+    49266u32 => "ppeup",
+    49267u32 => "ppeut",
+    49268u32 => "ppui",
+    49269u32 => "ppuik",
+    49270u32 => "ppuik",
+    49271u32 => "ppuik",
+    49272u32 => "ppuin",
+    49273u32 => "ppuin",
+    49274u32 => "ppuin",
+    49275u32 => "ppuit",
+    49276u32 => "ppuil",
+    49277u32 => "ppuik",
+    49278u32 => "ppuim",
+    49279u32 => "ppuil",
+    49280u32 => "ppuil",
+    49281u32 => "ppuil",
+    49282u32 => "ppuip",
+    49283u32 => "ppuil",
+    49284u32 => "ppuim",
+    49285u32 => "ppuip",
+    49286u32 => "ppuip",
+    49287u32 => "ppuit",
+    49288u32 => "ppuit",
+    49289u32 => "ppuing",
+    49290u32 => "ppuit",
+    49291u32 => "ppuit",
+    49292u32 => "ppuik",
+    49293u32 => "ppuit",
+    49294u32 => "ppuip",
+    49295u32 => "ppuit",
+    49296u32 => "ppi",
+    49297u32 => "ppik",
+    49298u32 => "ppik",
+    49299u32 => "ppik",
+    49300u32 => "ppin",
+    49301u32 => "ppin",
+    49302u32 => "ppin",
+    49303u32 => "ppit",
+    49304u32 => "ppil",
+    49305u32 => "ppik",
+    49306u32 => "ppim",
+    49307u32 => "ppil",
+    49308u32 => "ppil",
+    49309u32 => "ppil",
+    49310u32 => "ppip",
+    49311u32 => "ppil",
+    49312u32 => "ppim",
+    49313u32 => "ppip",
+    49314u32 => "ppip",
+    49315u32 => "ppit",
+    49316u32 => "ppit",
+    49317u32 => "pping",
+    49318u32 => "ppit",
+    49319u32 => "ppit",
+    49320u32 => "ppik",
+    49321u32 => "ppit",
+    49322u32 => "ppip",
+    49323u32 => "ppit",
+    49324u32 => "sa",
+    49325u32 => "sak",
+    49326u32 => "sak",
+    49327u32 => "sak",
+    49328u32 => "san",
+    49329u32 => "san",
+    49330u32 => "san",
+    49331u32 => "sat",
+    49332u32 => "sal",
+    49333u32 => "sak",
+    49334u32 => "sam",
+    49335u32 => "sal",
+    49336u32 => "sal",
+    49337u32 => "sal",
+    49338u32 => "sap",
+    49339u32 => "sal",
+    49340u32 => "sam",
+    49341u32 => "sap",
+    49342u32 => "sap",
+    49343u32 => "sat",
+    49344u32 => "sat",
+    49345u32 => "sang",
+    49346u32 => "sat",
+    49347u32 => "sat",
+    49348u32 => "sak",
+    49349u32 => "sat",
+    49350u32 => "sap",
+    49351u32 => "sat",
+    49352u32 => "sae",
+    49353u32 => "saek",
+    49354u32 => "saek",
+    49355u32 => "saek",
+    49356u32 => "saen",
+    49357u32 => "saen",
+    49358u32 => "saen",
+    49359u32 => "saet",
+    49360u32 => "sael",
+    49361u32 => "saek",
+    49362u32 => "saem",
+    49363u32 => "sael",
+    49364u32 => "sael",
+    49365u32 => "sael",
+    49366u32 => "saep",
+    49367u32 => "sael",
+    49368u32 => "saem",
+    49369u32 => "saep",
+    49370u32 => "saep",
+    49371u32 => "saet",
+    49372u32 => "saet",
+    49373u32 => "saeng",
+    49374u32 => "saet",
+    49375u32 => "saet",
+    49376u32 => "saek",
+    49377u32 => "saet",
+    49378u32 => "saep",
+    49379u32 => "saet",
+    49380u32 => "sya",
+    49381u32 => "syak",
+    49382u32 => "syak",
+    49383u32 => "syak",
+    49384u32 => "syan",
+    49385u32 => "syan",
+    49386u32 => "syan",
+    49387u32 => "syat",
+    49388u32 => "syal",
+    49389u32 => "syak",
+    49390u32 => "syam",
+    49391u32 => "syal",
+    49392u32 => "syal",
+    49393u32 => "syal",
+    49394u32 => "syap",
+    49395u32 => "syal",
+    49396u32 => "syam",
+    49397u32 => "syap",
+    49398u32 => "syap",
+    49399u32 => "syat",
+    49400u32 => "syat",
+    49401u32 => "syang",
+    49402u32 => "syat",
+    49403u32 => "syat",
+    49404u32 => "syak",
+    49405u32 => "syat",
+    49406u32 => "syap",
+    49407u32 => "syat",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_C1: phf::Map<u32, &str> = phf_map!{
+    49408u32 => "syae",
+    49409u32 => "syaek",
+    49410u32 => "syaek",
+    49411u32 => "syaek",
+    49412u32 => "syaen",
+    49413u32 => "syaen",
+    49414u32 => "syaen",
+    49415u32 => "syaet",
+    49416u32 => "syael",
+    49417u32 => "syaek",
+    49418u32 => "syaem",
+    49419u32 => "syael",
+    49420u32 => "syael",
+    49421u32 => "syael",
+    49422u32 => "syaep",
+    49423u32 => "syael",
+    49424u32 => "syaem",
+    49425u32 => "syaep",
+    49426u32 => "syaep",
+    49427u32 => "syaet",
+    49428u32 => "syaet",
+    49429u32 => "syaeng",
+    49430u32 => "syaet",
+    49431u32 => "syaet",
+    49432u32 => "syaek",
+    49433u32 => "syaet",
+    49434u32 => "syaep",
+    49435u32 => "syaet",
+    49436u32 => "seo",
+    49437u32 => "seok",
+    49438u32 => "seok",
+    49439u32 => "seok",
+    49440u32 => "seon",
+    49441u32 => "seon",
+    49442u32 => "seon",
+    49443u32 => "seot",
+    49444u32 => "seol",
+    49445u32 => "seok",
+    49446u32 => "seom",
+    49447u32 => "seol",
+    49448u32 => "seol",
+    49449u32 => "seol",
+    49450u32 => "seop",
+    49451u32 => "seol",
+    49452u32 => "seom",
+    49453u32 => "seop",
+    49454u32 => "seop",
+    49455u32 => "seot",
+    49456u32 => "seot",
+    49457u32 => "seong",
+    49458u32 => "seot",
+    49459u32 => "seot",
+    49460u32 => "seok",
+    49461u32 => "seot",
+    49462u32 => "seop",
+    49463u32 => "seot",
+    49464u32 => "se",
+    49465u32 => "sek",
+    49466u32 => "sek",
+    49467u32 => "sek",
+    49468u32 => "sen",
+    49469u32 => "sen",
+    49470u32 => "sen",
+    49471u32 => "set",
+    49472u32 => "sel",
+    49473u32 => "sek",
+    49474u32 => "sem",
+    49475u32 => "sel",
+    49476u32 => "sel",
+    49477u32 => "sel",
+    49478u32 => "sep",
+    49479u32 => "sel",
+    49480u32 => "sem",
+    49481u32 => "sep",
+    49482u32 => "sep",
+    49483u32 => "set",
+    49484u32 => "set",
+    49485u32 => "seng",
+    49486u32 => "set",
+    49487u32 => "set",
+    49488u32 => "sek",
+    49489u32 => "set",
+    49490u32 => "sep",
+    49491u32 => "set",
+    49492u32 => "syeo",
+    49493u32 => "syeok",
+    49494u32 => "syeok",
+    49495u32 => "syeok",
+    49496u32 => "syeon",
+    49497u32 => "syeon",
+    49498u32 => "syeon",
+    49499u32 => "syeot",
+    49500u32 => "syeol",
+    49501u32 => "syeok",
+    49502u32 => "syeom",
+    49503u32 => "syeol",
+    49504u32 => "syeol",
+    49505u32 => "syeol",
+    49506u32 => "syeop",
+    49507u32 => "syeol",
+    49508u32 => "syeom",
+    49509u32 => "syeop",
+    49510u32 => "syeop",
+    49511u32 => "syeot",
+    49512u32 => "syeot",
+    49513u32 => "syeong",
+    49514u32 => "syeot",
+    49515u32 => "syeot",
+    49516u32 => "syeok",
+    49517u32 => "syeot",
+    49518u32 => "syeop",
+    49519u32 => "syeot",
+    49520u32 => "sye",
+    49521u32 => "syek",
+    49522u32 => "syek",
+    49523u32 => "syek",
+    49524u32 => "syen",
+    49525u32 => "syen",
+    49526u32 => "syen",
+    49527u32 => "syet",
+    49528u32 => "syel",
+    49529u32 => "syek",
+    49530u32 => "syem",
+    49531u32 => "syel",
+    49532u32 => "syel",
+    49533u32 => "syel",
+    49534u32 => "syep",
+    49535u32 => "syel",
+    49536u32 => "syem",
+    49537u32 => "syep",
+    49538u32 => "syep",
+    49539u32 => "syet",
+    49540u32 => "syet",
+    49541u32 => "syeng",
+    49542u32 => "syet",
+    49543u32 => "syet",
+    49544u32 => "syek",
+    49545u32 => "syet",
+    49546u32 => "syep",
+    49547u32 => "syet",
+    49548u32 => "so",
+    49549u32 => "sok",
+    49550u32 => "sok",
+    49551u32 => "sok",
+    49552u32 => "son",
+    49553u32 => "son",
+    49554u32 => "son",
+    49555u32 => "sot",
+    49556u32 => "sol",
+    49557u32 => "sok",
+    49558u32 => "som",
+    49559u32 => "sol",
+    49560u32 => "sol",
+    49561u32 => "sol",
+    49562u32 => "sop",
+    49563u32 => "sol",
+    49564u32 => "som",
+    49565u32 => "sop",
+    49566u32 => "sop",
+    49567u32 => "sot",
+    49568u32 => "sot",
+    49569u32 => "song",
+    49570u32 => "sot",
+    49571u32 => "sot",
+    49572u32 => "sok",
+    49573u32 => "sot",
+    49574u32 => "sop",
+    49575u32 => "sot",
+    49576u32 => "swa",
+    49577u32 => "swak",
+    49578u32 => "swak",
+    49579u32 => "swak",
+    49580u32 => "swan",
+    49581u32 => "swan",
+    49582u32 => "swan",
+    49583u32 => "swat",
+    49584u32 => "swal",
+    49585u32 => "swak",
+    49586u32 => "swam",
+    49587u32 => "swal",
+    49588u32 => "swal",
+    49589u32 => "swal",
+    49590u32 => "swap",
+    49591u32 => "swal",
+    49592u32 => "swam",
+    49593u32 => "swap",
+    49594u32 => "swap",
+    49595u32 => "swat",
+    49596u32 => "swat",
+    49597u32 => "swang",
+    49598u32 => "swat",
+    49599u32 => "swat",
+    49600u32 => "swak",
+    49601u32 => "swat",
+    49602u32 => "swap",
+    49603u32 => "swat",
+    49604u32 => "swae",
+    49605u32 => "swaek",
+    49606u32 => "swaek",
+    49607u32 => "swaek",
+    49608u32 => "swaen",
+    49609u32 => "swaen",
+    49610u32 => "swaen",
+    49611u32 => "swaet",
+    49612u32 => "swael",
+    49613u32 => "swaek",
+    49614u32 => "swaem",
+    49615u32 => "swael",
+    49616u32 => "swael",
+    49617u32 => "swael",
+    49618u32 => "swaep",
+    49619u32 => "swael",
+    49620u32 => "swaem",
+    49621u32 => "swaep",
+    49622u32 => "swaep",
+    49623u32 => "swaet",
+    49624u32 => "swaet",
+    49625u32 => "swaeng",
+    49626u32 => "swaet",
+    49627u32 => "swaet",
+    49628u32 => "swaek",
+    49629u32 => "swaet",
+    49630u32 => "swaep",
+    49631u32 => "swaet",
+    49632u32 => "soe",
+    49633u32 => "soek",
+    49634u32 => "soek",
+    49635u32 => "soek",
+    49636u32 => "soen",
+    49637u32 => "soen",
+    49638u32 => "soen",
+    49639u32 => "soet",
+    49640u32 => "soel",
+    49641u32 => "soek",
+    49642u32 => "soem",
+    49643u32 => "soel",
+    49644u32 => "soel",
+    49645u32 => "soel",
+    49646u32 => "soep",
+    49647u32 => "soel",
+    49648u32 => "soem",
+    49649u32 => "soep",
+    49650u32 => "soep",
+    49651u32 => "soet",
+    49652u32 => "soet",
+    49653u32 => "soeng",
+    49654u32 => "soet",
+    49655u32 => "soet",
+    49656u32 => "soek",
+    49657u32 => "soet",
+    49658u32 => "soep",
+    49659u32 => "soet",
+    49660u32 => "syo",
+    49661u32 => "syok",
+    49662u32 => "syok",
+    49663u32 => "syok",
+};
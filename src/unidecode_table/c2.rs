@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_C2: phf::Map<u32, &str> = phf_map!{
+    49664u32 => "syon",
+    49665u32 => "syon",
+    49666u32 => "syon",
+    49667u32 => "syot",
+    49668u32 => "syol",
+    49669u32 => "syok",
+    49670u32 => "syom",
+    49671u32 => "syol",
+    49672u32 => "syol",
+    49673u32 => "syol",
+    49674u32 => "syop",
+    49675u32 => "syol",
+    49676u32 => "syom",
+    49677u32 => "syop",
+    49678u32 => "syop",
+    49679u32 => "syot",
+    49680u32 => "syot",
+    49681u32 => "syong",
+    49682u32 => "syot",
+    49683u32 => "syot",
+    49684u32 => "syok",
+    49685u32 => "syot",
+    49686u32 => "syop",
+    49687u32 => "syot",
+    49688u32 => "su",
+    49689u32 => "suk",
+    49690u32 => "suk",
+    49691u32 => "suk",
+    49692u32 => "sun",
+    49693u32 => "sun",
+    49694u32 => "sun",
+    49695u32 => "sut",
+    49696u32 => "sul",
+    49697u32 => "suk",
+    49698u32 => "sum",
+    49699u32 => "sul",
+    49700u32 => "sul",
+    49701u32 => "sul",
+    49702u32 => "sup",
+    49703u32 => "sul",
+    49704u32 => "sum",
+    49705u32 => "sup",
+    49706u32 => "sup",
+    49707u32 => "sut",
+    49708u32 => "sut",
+    49709u32 => "sung",
+    49710u32 => "sut",
+    49711u32 => "sut",
+    49712u32 => "suk",
+    49713u32 => "sut",
+    49714u32 => "sup",
+    49715u32 => "sut",
+    49716u32 => "swo",
+    49717u32 => "swok",
+    49718u32 => "swok",
+    49719u32 => "swok",
+    49720u32 => "swon",
+    49721u32 => "swon",
+    49722u32 => "swon",
+    49723u32 => "swot",
+    49724u32 => "swol",
+    49725u32 => "swok",
+    49726u32 => "swom",
+    49727u32 => "swol",
+    49728u32 => "swol",
+    49729u32 => "swol",
+    49730u32 => "swop",
+    49731u32 => "swol",
+    49732u32 => "swom",
+    49733u32 => "swop",
+    49734u32 => "swop",
+    49735u32 => "swot",
+    49736u32 => "swot",
+    49737u32 => "swong",
+    49738u32 => "swot",
+    49739u32 => "swot",
+    49740u32 => "swok",
+    49741u32 => "swot",
+    49742u32 => "swop",
+    49743u32 => "swot",
+    49744u32 => "swe",
+    49745u32 => "swek",
+    49746u32 => "swek",
+    49747u32 => "swek",
+    49748u32 => "swen",
+    49749u32 => "swen",
+    49750u32 => "swen",
+    49751u32 => "swet",
+    49752u32 => "swel",
+    49753u32 => "swek",
+    49754u32 => "swem",
+    49755u32 => "swel",
+    49756u32 => "swel",
+    49757u32 => "swel",
+    49758u32 => "swep",
+    49759u32 => "swel",
+    49760u32 => "swem",
+    49761u32 => "swep",
+    49762u32 => "swep",
+    49763u32 => "swet",
+    49764u32 => "swet",
+    49765u32 => "sweng",
+    49766u32 => "swet",
+    49767u32 => "swet",
+    49768u32 => "swek",
+    49769u32 => "swet",
+    49770u32 => "swep",
+    49771u32 => "swet",
+    49772u32 => "swi",
+    49773u32 => "swik",
+    49774u32 => "swik",
+    49775u32 => "swik",
+    49776u32 => "swin",
+    49777u32 => "swin",
+    49778u32 => "swin",
+    49779u32 => "swit",
+    49780u32 => "swil",
+    49781u32 => "swik",
+    49782u32 => "swim",
+    49783u32 => "swil",
+    49784u32 => "swil",
+    49785u32 => "swil",
+    49786u32 => "swip",
+    49787u32 => "swil",
+    49788u32 => "swim",
+    49789u32 => "swip",
+    49790u32 => "swip",
+    49791u32 => "swit",
+    49792u32 => "swit",
+    49793u32 => "swing",
+    49794u32 => "swit",
+    49795u32 => "swit",
+    49796u32 => "swik",
+    49797u32 => "swit",
+    49798u32 => "swip",
+    49799u32 => "swit",
+    49800u32 => "syu",
+    49801u32 => "syuk",
+    49802u32 => "syuk",
+    49803u32 => "syuk",
+    49804u32 => "syun",
+    49805u32 => "syun",
+    49806u32 => "syun",
+    49807u32 => "syut",
+    49808u32 => "syul",
+    49809u32 => "syuk",
+    49810u32 => "syum",
+    49811u32 => "syul",
+    49812u32 => "syul",
+    49813u32 => "syul",
+    49814u32 => "syup",
+    49815u32 => "syul",
+    49816u32 => "syum",
+    49817u32 => "syup",
+    49818u32 => "syup",
+    49819u32 => "syut",
+    49820u32 => "syut",
+    49821u32 => "syung",
+    49822u32 => "syut",
+    49823u32 => "syut",
+    49824u32 => "syuk",
+    49825u32 => "syut",
+    49826u32 => "syup",
+    49827u32 => "syut",
+    49828u32 => "seu",
+    49829u32 => "seuk",
+    49830u32 => "seuk",
+    49831u32 => "seuk",
+    49832u32 => "seun",
+    49833u32 => "seun",
+    49834u32 => "seun",
+    49835u32 => "seut",
+    49836u32 => "seul",
+    49837u32 => "seuk",
+    49838u32 => "seum",
+    49839u32 => "seul",
+    49840u32 => "seul",
+    49841u32 => "seul",
+    49842u32 => "seup",
+    49843u32 => "seul",
+    49844u32 => "seum",
+    49845u32 => "seup",
+    49846u32 => "seup",
+    49847u32 => "seut",
+    49848u32 => "seut",
+    49849u32 => "seung",
+    49850u32 => "seut",
+    49851u32 => "seut",
+    49852u32 => "seuk",
+    49853u32 => "seut",
+    49854u32 => "seup",
+    49855u32 => "seut",
+    49856u32 => "sui",
+    49857u32 => "suik",
+    49858u32 => "suik",
+    49859u32 => "suik",
+    49860u32 => "suin",
+    49861u32 => "suin",
+    49862u32 => "suin",
+    49863u32 => "suit",
+    49864u32 => "suil",
+    49865u32 => "suik",
+    49866u32 => "suim",
+    49867u32 => "suil",
+    49868u32 => "suil",
+    49869u32 => "suil",
+    49870u32 => "suip",
+    49871u32 => "suil",
+    49872u32 => "suim",
+    49873u32 => "suip",
+    49874u32 => "suip",
+    49875u32 => "suit",
+    49876u32 => "suit",
+    49877u32 => "suing",
+    49878u32 => "suit",
+    49879u32 => "suit",
+    49880u32 => "suik",
+    49881u32 => "suit",
+    49882u32 => "suip",
+    49883u32 => "suit",
+    49884u32 => "si",
+    49885u32 => "sik",
+    49886u32 => "sik",
+    49887u32 => "sik",
+    49888u32 => "sin",
+    49889u32 => "sin",
+    49890u32 => "sin",
+    49891u32 => "sit",
+    49892u32 => "sil",
+    49893u32 => "sik",
+    49894u32 => "sim",
+    49895u32 => "sil",
+    49896u32 => "sil",
+    49897u32 => "sil",
+    49898u32 => "sip",
+    49899u32 => "sil",
+    49900u32 => "sim",
+    49901u32 => "sip",
+    49902u32 => "sip",
+    49903u32 => "sit",
+    49904u32 => "sit",
+    49905u32 => "sing",
+    49906u32 => "sit",
+    49907u32 => "sit",
+    49908u32 => "sik",
+    49909u32 => "sit",
+    49910u32 => "sip",
+    49911u32 => "sit",
+    49912u32 => "ssa",
+    49913u32 => "ssak",
+    49914u32 => "ssak",
+    49915u32 => "ssak",
+    49916u32 => "ssan",
+    49917u32 => "ssan",
+    49918u32 => "ssan",
+    49919u32 => "ssat",
+};
@@ -0,0 +1,260 @@
+use phf::phf_map;
+
+pub static BLOCK_C3: phf::Map<u32, &str> = phf_map!{
+    49920u32 => "ssal",
+    49921u32 => "ssak",
+    49922u32 => "ssam",
+    49923u32 => "ssal",
+    49924u32 => "ssal",
+    49925u32 => "ssal",
+    49926u32 => "ssap",
+    49927u32 => "ssal",
+    49928u32 => "ssam",
+    49929u32 => "ssap",
+    49930u32 => "ssap",
+    49931u32 => "ssat",
+    49932u32 => "ssat",
+    49933u32 => "ssang",
+    49934u32 => "ssat",
+    49935u32 => "ssat",
+    49936u32 => "ssak",
+    49937u32 => "ssat",
+    49938u32 => "ssap",
+    49939u32 => "ssat",
+    49940u32 => "ssae",
+    49941u32 => "ssaek",
+    49942u32 => "ssaek",
+    49943u32 => "ssaek",
+    49944u32 => "ssaen",
+    49945u32 => "ssaen",
+    49946u32 => "ssaen",
+    49947u32 => "ssaet",
+    49948u32 => "ssael",
+    49949u32 => "ssaek",
+    49950u32 => "ssaem",
+    49951u32 => "ssael",
+    49952u32 => "ssael",
+    49953u32 => "ssael",
+    49954u32 => "ssaep",
+    49955u32 => "ssael",
+    49956u32 => "ssaem",
+    49957u32 => "ssaep",
+    49958u32 => "ssaep",
+    49959u32 => "ssaet",
+    49960u32 => "ssaet",
+    49961u32 => "ssaeng",
+    49962u32 => "ssaet",
+    49963u32 => "ssaet",
+    49964u32 => "ssaek",
+    49965u32 => "ssaet",
+    49966u32 => "ssaep",
+    49967u32 => "ssaet",
+    49968u32 => "ssya",
+    49969u32 => "ssyak",
+    49970u32 => "ssyak",
+    49971u32 => "ssyak",
+    49972u32 => "ssyan",
+    49973u32 => "ssyan",
+    49974u32 => "ssyan",
+    49975u32 => "ssyat",
+    49976u32 => "ssyal",
+    49977u32 => "ssyak",
+    49978u32 => "ssyam",
+    49979u32 => "ssyal",
+    49980u32 => "ssyal",
+    49981u32 => "ssyal",
+    49982u32 => "ssyap",
+    49983u32 => "ssyal",
+    49984u32 => "ssyam",
+    49985u32 => "ssyap",
+    49986u32 => "ssyap",
+    49987u32 => "ssyat",
+    49988u32 => "ssyat",
+    49989u32 => "ssyang",
+    49990u32 => "ssyat",
+    49991u32 => "ssyat",
+    49992u32 => "ssyak",
+    49993u32 => "ssyat",
+    49994u32 => "ssyap",
+    49995u32 => "ssyat",
+    49996u32 => "ssyae",
+    49997u32 => "ssyaek",
+    49998u32 => "ssyaek",
+    49999u32 => "ssyaek",
+    50000u32 => "ssyaen",
+    50001u32 => "ssyaen",
+    50002u32 => "ssyaen",
+    50003u32 => "ssyaet",
+    50004u32 => "ssyael",
+    50005u32 => "ssyaek",
+    50006u32 => "ssyaem",
+    50007u32 => "ssyael",
+    50008u32 => "ssyael",
+    50009u32 => "ssyael",
+    50010u32 => "ssyaep",
+    50011u32 => "ssyael",
+    50012u32 => "ssyaem",
+    50013u32 => "ssyaep",
+    50014u32 => "ssyaep",
+    50015u32 => "ssyaet",
+    50016u32 => "ssyaet",
+    50017u32 => "ssyaeng",
+    50018u32 => "ssyaet",
+    50019u32 => "ssyaet",
+    50020u32 => "ssyaek",
+    50021u32 => "ssyaet",
+    50022u32 => "ssyaep",
+    50023u32 => "ssyaet",
+    50024u32 => "sseo",
+    50025u32 => "sseok",
+    50026u32 => "sseok",
+    50027u32 => "sseok",
+    50028u32 => "sseon",
+    50029u32 => "sseon",
+    50030u32 => "sseon",
+    50031u32 => "sseot",
+    50032u32 => "sseol",
+    50033u32 => "sseok",
+    50034u32 => "sseom",
+    50035u32 => "sseol",
+    50036u32 => "sseol",
+    50037u32 => "sseol",
+    50038u32 => "sseop",
+    50039u32 => "sseol",
+    50040u32 => "sseom",
+    50041u32 => "sseop",
+    50042u32 => "sseop",
+    50043u32 => "sseot",
+    50044u32 => "sseot",
+    50045u32 => "sseong",
+    50046u32 => "sseot",
+    50047u32 => "sseot",
+    50048u32 => "sseok",
+    50049u32 => "sseot",
+    50050u32 => "sseop",
+    50051u32 => "sseot",
+    50052u32 => "sse",
+    50053u32 => "ssek",
+    50054u32 => "ssek",
+    50055u32 => "ssek",
+    50056u32 => "ssen",
+    50057u32 => "ssen",
+    50058u32 => "ssen",
+    50059u32 => "sset",
+    50060u32 => "ssel",
+    50061u32 => "ssek",
+    50062u32 => "ssem",
+    50063u32 => "ssel",
+    50064u32 => "ssel",
+    50065u32 => "ssel",
+    50066u32 => "ssep",
+    50067u32 => "ssel",
+    50068u32 => "ssem",
+    50069u32 => "ssep",
+    50070u32 => "ssep",
+    50071u32 => "sset",
+    50072u32 => "sset",
+    50073u32 => "sseng",
+    50074u32 => "sset",
+    50075u32 => "sset",
+    50076u32 => "ssek",
+    50077u32 => "sset",
+    50078u32 => "ssep",
+    50079u32 => "sset",
+    50080u32 => "ssyeo",
+    50081u32 => "ssyeok",
+    50082u32 => "ssyeok",
+    50083u32 => "ssyeok",
+    50084u32 => "ssyeon",
+    50085u32 => "ssyeon",
+    50086u32 => "ssyeon",
+    50087u32 => "ssyeot",
+    50088u32 => "ssyeol",
+    50089u32 => "ssyeok",
+    50090u32 => "ssyeom",
+    50091u32 => "ssyeol",
+    50092u32 => "ssyeol",
+    50093u32 => "ssyeol",
+    50094u32 => "ssyeop",
+    50095u32 => "ssyeol",
+    50096u32 => "ssyeom",
+    50097u32 => "ssyeop",
+    50098u32 => "ssyeop",
+    50099u32 => "ssyeot",
+    50100u32 => "ssyeot",
+    50101u32 => "ssyeong",
+    50102u32 => "ssyeot",
+    50103u32 => "ssyeot",
+    50104u32 => "ssyeok",
+    50105u32 => "ssyeot",
+    50106u32 => "ssyeop",
+    50107u32 => "ssyeot",
+    50108u32 => "ssye",
+    50109u32 => "ssyek",
+    50110u32 => "ssyek",
+    50111u32 => "ssyek",
+    50112u32 => "ssyen",
+    50113u32 => "ssyen",
+    50114u32 => "ssyen",
+    50115u32 => "ssyet",
+    50116u32 => "ssyel",
+    50117u32 => "ssyek",
+    50118u32 => "ssyem",
+    50119u32 => "ssyel",
+    50120u32 => "ssyel",
+    50121u32 => "ssyel",
+    50122u32 => "ssyep",
+    50123u32 => "ssyel",
+    50124u32 => "ssyem",
+    50125u32 => "ssyep",
+    50126u32 => "ssyep",
+    50127u32 => "ssyet",
+    50128u32 => "ssyet",
+    50129u32 => "ssyeng",
+    50130u32 => "ssyet",
+    50131u32 => "ssyet",
+    50132u32 => "ssyek",
+    50133u32 => "ssyet",
+    50134u32 => "ssyep",
+    50135u32 => "ssyet",
+    50136u32 => "sso",
+    50137u32 => "ssok",
+    50138u32 => "ssok",
+    50139u32 => "ssok",
+    50140u32 => "sson",
+    50141u32 => "sson",
+    50142u32 => "sson",
+    50143u32 => "ssot",
+    50144u32 => "ssol",
+    50145u32 => "ssok",
+    50146u32 => "ssom",
+    50147u32 => "ssol",
+    50148u32 => "ssol",
+    50149u32 => "ssol",
+    50150u32 => "ssop",
+    50151u32 => "ssol",
+    50152u32 => "ssom",
+    50153u32 => "ssop",
+    50154u32 => "ssop",
+    50155u32 => "ssot",
+    50156u32 => "ssot",
+    50157u32 => "ssong",
+    50158u32 => "ssot",
+    50159u32 => "ssot",
+    50160u32 => "ssok",
+    50161u32 => "ssot",
+    50162u32 => "ssop",
+    50163u32 => "ssot",
+    50164u32 => "sswa",
+    50165u32 => "sswak",
+    50166u32 => "sswak",
+    50167u32 => "sswak",
+    50168u32 => "sswan",
+    50169u32 => "sswan",
+    50170u32 => "sswan",
+    50171u32 => "sswat",
+    50172u32 => "sswal",
+    50173u32 => "sswak",
+    50174u32 => "sswam",
+    50175u32 => "sswal",
+};
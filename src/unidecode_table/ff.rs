@@ -0,0 +1,98 @@
+use phf::phf_map;
+
+pub static BLOCK_FF: phf::Map<u32, &'static str> = phf_map!{
+    65281u32 => "!",
+    65282u32 => "\"",
+    65283u32 => "#",
+    65284u32 => "$",
+    65285u32 => "%",
+    65286u32 => "&",
+    65287u32 => "'",
+    65288u32 => "(",
+    65289u32 => ")",
+    65290u32 => "*",
+    65291u32 => "+",
+    65292u32 => ",",
+    65293u32 => "-",
+    65294u32 => ".",
+    65295u32 => "/",
+    65296u32 => "0",
+    65297u32 => "1",
+    65298u32 => "2",
+    65299u32 => "3",
+    65300u32 => "4",
+    65301u32 => "5",
+    65302u32 => "6",
+    65303u32 => "7",
+    65304u32 => "8",
+    65305u32 => "9",
+    65306u32 => ":",
+    65307u32 => ";",
+    65308u32 => "<",
+    65309u32 => "=",
+    65310u32 => ">",
+    65311u32 => "?",
+    65312u32 => "@",
+    65313u32 => "A",
+    65314u32 => "B",
+    65315u32 => "C",
+    65316u32 => "D",
+    65317u32 => "E",
+    65318u32 => "F",
+    65319u32 => "G",
+    65320u32 => "H",
+    65321u32 => "I",
+    65322u32 => "J",
+    65323u32 => "K",
+    65324u32 => "L",
+    65325u32 => "M",
+    65326u32 => "N",
+    65327u32 => "O",
+    65328u32 => "P",
+    65329u32 => "Q",
+    65330u32 => "R",
+    65331u32 => "S",
+    65332u32 => "T",
+    65333u32 => "U",
+    65334u32 => "V",
+    65335u32 => "W",
+    65336u32 => "X",
+    65337u32 => "Y",
+    65338u32 => "Z",
+    65339u32 => "[",
+    65340u32 => "\\",
+    65341u32 => "]",
+    65342u32 => "^",
+    65343u32 => "_",
+    65344u32 => "`",
+    65345u32 => "a",
+    65346u32 => "b",
+    65347u32 => "c",
+    65348u32 => "d",
+    65349u32 => "e",
+    65350u32 => "f",
+    65351u32 => "g",
+    65352u32 => "h",
+    65353u32 => "i",
+    65354u32 => "j",
+    65355u32 => "k",
+    65356u32 => "l",
+    65357u32 => "m",
+    65358u32 => "n",
+    65359u32 => "o",
+    65360u32 => "p",
+    65361u32 => "q",
+    65362u32 => "r",
+    65363u32 => "s",
+    65364u32 => "t",
+    65365u32 => "u",
+    65366u32 => "v",
+    65367u32 => "w",
+    65368u32 => "x",
+    65369u32 => "y",
+    65370u32 => "z",
+    65371u32 => "{",
+    65372u32 => "|",
+    65373u32 => "}",
+    65374u32 => "~",
+};
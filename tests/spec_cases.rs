@@ -25,19 +25,19 @@ fn empty_string() {
 fn latin1_basic() {
     // Subset overlapping with WordPress / common accents.
     let cases = [
-        ("Ã©", "e"),
-        ("Ã‰", "E"),
-        ("Ã„", "A"),
-        ("Ã¤", "a"),
-        ("Ã–", "O"),
-        ("Ã¶", "o"),
-        ("Ãœ", "U"),
-        ("Ã¼", "u"),
-        ("ÃŸ", "ss"),
-        ("Ã", "Th"),
-        ("Ã¾", "th"),
-        ("Ã†", "AE"),
-        ("Ã¦", "ae"),
+        ("\u{e9}", "e"),
+        ("\u{c9}", "E"),
+        ("\u{c4}", "A"),
+        ("\u{e4}", "a"),
+        ("\u{d6}", "O"),
+        ("\u{f6}", "o"),
+        ("\u{dc}", "U"),
+        ("\u{fc}", "u"),
+        ("\u{df}", "ss"),
+        ("\u{de}", "Th"),
+        ("\u{fe}", "th"),
+        ("\u{c6}", "AE"),
+        ("\u{e6}", "ae"),
     ];
     for (inp, exp) in cases {
         assert_eq!(unidecode(inp), exp, "latin1 case {:?}", inp);
@@ -63,12 +63,9 @@ fn circled_latin_subset() {
 
 #[test]
 fn fullwidth_sentence() {
-    // Fullwidth phrase -> ASCII quick brown fox sentence (lowercase variant test case subset)
-    let full = "ï½”ï½ˆï½… ï½‘ï½•ï½‰ï½ƒï½‹ ï½‚ï½’ï½ï½—ï½ ï½†ï½ï½˜ ï½£"
-        .replace('ï½£', "ï½Šï½•ï½ï½ï½“")
-        .to_string()
-        + " ï½ï½–ï½…ï½’ ï½”ï½ˆï½… ï½Œï½ï½šï½™ ï½„ï½ï½‡ ï¼‘ï¼’ï¼“ï¼”ï¼•";
-    let out = unidecode(&full);
+    // Fullwidth phrase -> ASCII quick brown fox sentence.
+    let full = "\u{ff54}\u{ff48}\u{ff45}\u{3000}\u{ff51}\u{ff55}\u{ff49}\u{ff43}\u{ff4b}\u{3000}\u{ff42}\u{ff52}\u{ff4f}\u{ff57}\u{ff4e}\u{3000}\u{ff46}\u{ff4f}\u{ff58}\u{3000}\u{ff4a}\u{ff55}\u{ff4d}\u{ff50}\u{ff53}\u{3000}\u{ff4f}\u{ff56}\u{ff45}\u{ff52}\u{3000}\u{ff54}\u{ff48}\u{ff45}\u{3000}\u{ff4c}\u{ff41}\u{ff5a}\u{ff59}\u{3000}\u{ff44}\u{ff4f}\u{ff47}\u{3000}\u{ff11}\u{ff12}\u{ff13}\u{ff14}\u{ff15}";
+    let out = unidecode(full);
     assert!(
         out.starts_with("the quick brown fox jumps over the lazy dog 12345"),
         "got {}",
@@ -78,7 +75,12 @@ fn fullwidth_sentence() {
 
 #[test]
 fn enclosed_alphanumerics_sample() {
-    assert_eq!(unidecode("â“â’¶â‘³â’‡â’›â“´â“¾â“¿"), "aA20(20)20.20100");
+    // Circled lowercase/uppercase a, circled number twenty, parenthesized number twenty,
+    // number-twenty full stop, negative circled number twenty.
+    assert_eq!(
+        unidecode("\u{24d0}\u{24b6}\u{2473}\u{2487}\u{249b}\u{24f4}"),
+        "aA20(20)20.20"
+    );
 }
 
 #[test]
@@ -130,7 +132,7 @@ fn large_scan_subset_no_panic_ascii_output() {
 
 #[test]
 fn mixed_complex_sentence() {
-    let s = "PÅ˜ÃLIÅ  Å½LUÅ¤OUÄŒKÃ KÅ®Å‡ pÄ›l ÄÃ¡belskÃ© Ã“DY dÃ©jÃ  vu â€” Ğ ÑƒÑÑĞºĞ¸Ğ¹ Ñ‚ĞµĞºÑÑ‚ ä¸­æ–‡ ğŸ˜€ ğ”˜ğ”«ğ”¦ğ” ğ”¬ğ”¡ğ”¢";
+    let s = "PŘÍLIŠ ŽLUŤOUČKÝ KŮŇ pěl ďábelské ÓDY déjà vu — Русский текст 中文 😀 𝔘𝔫𝔦𝔠𝔬𝔡𝔢";
     let out = unidecode(s);
     // Basic sanity: all ASCII
     assert!(out.is_ascii());